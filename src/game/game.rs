@@ -5,11 +5,15 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use std::panic::AssertUnwindSafe;
 use std::time::Duration;
 
 /// A `trait` runnable within a `GameLoop`. `dt` is the `Duration` since last frame. `update`
 /// should return the boolean value of whether the game should continue.
 ///
+/// All time spans in the engine are `std::time::Duration`s. Convert them to `f32`/`f64` seconds
+/// with `as_secs_f32`/`as_secs_f64` only at the boundary with the math layer or scripts.
+///
 /// # Examples
 ///
 /// ```
@@ -30,4 +34,26 @@ use std::time::Duration;
 /// ```
 pub trait Game {
     fn update(&self, dt: Duration) -> bool;
+
+    /// Called once by `GameLoop` before the first `update`. Does nothing by default.
+    fn start(&self) {}
+
+    /// Called once by `GameLoop` after the last `update`. Does nothing by default.
+    fn stop(&self) {}
+}
+
+/// Lets games that are not `RefUnwindSafe` run in `GameLoop::run_catching` by wrapping them in
+/// `AssertUnwindSafe`.
+impl<T: Game> Game for AssertUnwindSafe<T> {
+    fn update(&self, dt: Duration) -> bool {
+        self.0.update(dt)
+    }
+
+    fn start(&self) {
+        self.0.start();
+    }
+
+    fn stop(&self) {
+        self.0.stop();
+    }
 }
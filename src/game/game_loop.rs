@@ -7,10 +7,27 @@
 
 use super::game::Game;
 
-use std::time::Instant;
+use std::any::Any;
+use std::collections::VecDeque;
+use std::panic::{self, RefUnwindSafe};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const PAUSED_SLEEP_MILLIS: u64 = 10;
 
 /// A `struct` that helps you create a very simple game loop.
 ///
+/// All runs call the `Game`'s `start` before the first frame and `stop` after the last one.
+///
+/// The loop can be paused by setting `paused` to `true` from game code or from another thread.
+/// While paused, the loop keeps calling `update` (so that the game can still handle input and
+/// resume or quit), but feeds it a zero `Duration` and sleeps between frames. Time spent paused
+/// is never fed to `update` after resuming.
+///
+/// Frame deltas can optionally be smoothed, see `new_smoothed`.
+///
 /// # Examples
 ///
 /// ```
@@ -30,7 +47,12 @@ use std::time::Instant;
 /// GameLoop::new(MyGame).run();
 /// ```
 pub struct GameLoop<T: Game> {
-    pub game: T
+    pub game: T,
+    /// `Arc<AtomicBool>` flag pausing the loop while `true`
+    pub paused: Arc<AtomicBool>,
+    /// `usize` number of frame deltas averaged before being fed to `update`; `1` feeds raw
+    /// deltas
+    pub smoothing: usize
 }
 
 impl<T: Game> GameLoop<T> {
@@ -55,7 +77,79 @@ impl<T: Game> GameLoop<T> {
     /// GameLoop::new(MyGame);
     /// ```
     pub fn new(game: T) -> GameLoop<T> {
-        GameLoop { game: game }
+        GameLoop {
+            game: game,
+            paused: Arc::new(AtomicBool::new(false)),
+            smoothing: 1
+        }
+    }
+
+    /// Creates a `GameLoop` that feeds `update` the average of the last `window` frame deltas
+    /// instead of the raw ones, as computed by `DeltaSmoother`. Raw deltas jitter from frame to
+    /// frame even at a stable frame rate, which makes motion stutter.
+    ///
+    /// Larger windows smooth more, but take about `window` frames to follow real changes in
+    /// frame rate, e.g. after a hitch. A few frames (4 to 8) are usually enough. Smoothing
+    /// applies to `run`, `run_until` and `run_catching`; `run_fixed` always feeds `step`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use anima_engine::game::Game;
+    /// # use anima_engine::game::GameLoop;
+    /// pub struct MyGame;
+    ///
+    /// impl Game for MyGame {
+    ///     fn update(&self, dt: Duration) -> bool {
+    ///         // `dt` is averaged over the last 4 frames.
+    ///         false
+    ///     }
+    /// }
+    ///
+    /// GameLoop::new_smoothed(MyGame, 4).run();
+    /// ```
+    pub fn new_smoothed(game: T, window: usize) -> GameLoop<T> {
+        GameLoop {
+            game: game,
+            paused: Arc::new(AtomicBool::new(false)),
+            smoothing: window
+        }
+    }
+
+    /// Creates a `GameLoop` paused by a shared `paused` flag. Keep a clone of the flag in the
+    /// `Game` to let it pause and resume itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// # use std::time::Duration;
+    /// # use anima_engine::game::Game;
+    /// # use anima_engine::game::GameLoop;
+    /// pub struct MyGame {
+    ///     paused: Arc<AtomicBool>
+    /// }
+    ///
+    /// impl Game for MyGame {
+    ///     fn update(&self, dt: Duration) -> bool {
+    ///         // Toggle pause when the player presses the pause button.
+    ///         self.paused.store(true, Ordering::SeqCst);
+    ///         false
+    ///     }
+    /// }
+    ///
+    /// let paused = Arc::new(AtomicBool::new(false));
+    ///
+    /// GameLoop::new_pausable(MyGame { paused: paused.clone() }, paused).run();
+    /// ```
+    pub fn new_pausable(game: T, paused: Arc<AtomicBool>) -> GameLoop<T> {
+        GameLoop {
+            game: game,
+            paused: paused,
+            smoothing: 1
+        }
     }
 
     /// Runs `GameLoop`'s `Game` in a loop while feeding the time between frames to the `Game`'s
@@ -80,16 +174,318 @@ impl<T: Game> GameLoop<T> {
     /// GameLoop::new(MyGame).run();
     /// ```
     pub fn run(&self) {
-        let mut last = Instant::now();
+        self.run_until(|_, _| false);
+    }
 
-        loop {
+    /// Runs `GameLoop`'s `Game` like `run` until either `update` returns `false` or `condition`
+    /// returns `true`. Before every frame, `condition` receives the number of frames run so far
+    /// and the total time elapsed since the loop started, which is useful for running tests,
+    /// benchmarks or demos for a bounded number of frames or amount of time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use anima_engine::game::Game;
+    /// # use anima_engine::game::GameLoop;
+    /// pub struct MyGame;
+    ///
+    /// impl Game for MyGame {
+    ///     fn update(&self, dt: Duration) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// GameLoop::new(MyGame).run_until(|frames, elapsed| {
+    ///     frames == 100 || elapsed > Duration::from_secs(1)
+    /// });
+    /// ```
+    pub fn run_until<F>(&self, condition: F) where F: Fn(u64, Duration) -> bool {
+        let _ = self.run_frames(condition, |dt| Ok(self.game.update(dt)));
+    }
+
+    /// Runs `GameLoop`'s `Game` like `run`, but catches panics in `update` (e.g. coming from
+    /// mruby scripts) instead of letting them unwind through the loop. The panic's payload is
+    /// returned as `Err` after `stop` is called, letting the host show an error and shut down
+    /// cleanly.
+    ///
+    /// The `Game` must be `RefUnwindSafe`. Games using interior mutability (e.g. `Cell`) can be
+    /// wrapped in `AssertUnwindSafe` once it is clear that their state is not observed after a
+    /// panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use anima_engine::game::Game;
+    /// # use anima_engine::game::GameLoop;
+    /// pub struct MyGame;
+    ///
+    /// impl Game for MyGame {
+    ///     fn update(&self, dt: Duration) -> bool {
+    ///         panic!("script error");
+    ///     }
+    /// }
+    ///
+    /// assert!(GameLoop::new(MyGame).run_catching().is_err());
+    /// ```
+    pub fn run_catching(&self) -> Result<(), Box<dyn Any + Send>> where T: RefUnwindSafe {
+        let game = &self.game;
+
+        self.run_frames(|_, _| false, |dt| panic::catch_unwind(|| game.update(dt)))
+    }
+
+    fn run_frames<F, U>(&self, condition: F, mut update: U) -> Result<(), Box<dyn Any + Send>>
+        where F: Fn(u64, Duration) -> bool,
+              U: FnMut(Duration) -> Result<bool, Box<dyn Any + Send>> {
+        let begin = Instant::now();
+        let mut last = begin;
+        let mut frames = 0;
+        let mut smoother = DeltaSmoother::new(self.smoothing);
+
+        let mut result = Ok(());
+
+        self.game.start();
+
+        while !condition(frames, last - begin) {
+            frames += 1;
+
+            let paused = self.paused.load(Ordering::SeqCst);
             let start = Instant::now();
 
-            if !self.game.update(start - last) {
-                break;
+            let dt = if paused {
+                Duration::from_millis(0)
+            } else {
+                smoother.smooth(start - last)
+            };
+
+            match update(dt) {
+                Ok(true)   => (),
+                Ok(false)  => break,
+                Err(error) => {
+                    result = Err(error);
+
+                    break;
+                }
             }
 
+            if paused {
+                thread::sleep(Duration::from_millis(PAUSED_SLEEP_MILLIS));
+                last = Instant::now();
+            } else {
+                last = start;
+            }
+        }
+
+        self.game.stop();
+
+        result
+    }
+
+    /// Runs `GameLoop`'s `Game` in a loop with a fixed timestep. `update` is always fed `step`
+    /// and is called as many times as needed to catch up with real time, after which `render`
+    /// is called once per frame.
+    ///
+    /// `render` receives the interpolation factor `alpha` (between `0.0` and `1.0`), i.e. how far
+    /// real time is between the last `update` and the next one. Rendering the state kept from
+    /// before the last `update` interpolated towards the current one, e.g.
+    /// `previous.interpolate(current, alpha)` with the `Interpolate` trait, keeps motion smooth
+    /// when the frame rate and the update rate differ.
+    ///
+    /// While paused, `update` is fed a zero `Duration` instead of `step` and `render` keeps
+    /// receiving the same `alpha`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, like `FixedTimestep::new`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use anima_engine::game::Game;
+    /// # use anima_engine::game::GameLoop;
+    /// pub struct MyGame;
+    ///
+    /// impl Game for MyGame {
+    ///     fn update(&self, dt: Duration) -> bool {
+    ///         // `dt` is always 1/60 s.
+    ///         false
+    ///     }
+    /// }
+    ///
+    /// GameLoop::new(MyGame).run_fixed(Duration::from_secs(1) / 60, |alpha| {
+    ///     // Render state interpolated by `alpha`.
+    /// });
+    /// ```
+    pub fn run_fixed<F>(&self, step: Duration, mut render: F) where F: FnMut(f32) {
+        let mut timestep = FixedTimestep::new(step);
+        let mut last = Instant::now();
+
+        self.game.start();
+
+        'frames: loop {
+            if self.paused.load(Ordering::SeqCst) {
+                if !self.game.update(Duration::from_millis(0)) {
+                    break;
+                }
+
+                render(timestep.alpha());
+
+                thread::sleep(Duration::from_millis(PAUSED_SLEEP_MILLIS));
+                last = Instant::now();
+
+                continue;
+            }
+
+            let start = Instant::now();
+
+            timestep.advance(start - last);
             last = start;
+
+            while timestep.consume() {
+                if !self.game.update(step) {
+                    break 'frames;
+                }
+            }
+
+            render(timestep.alpha());
+        }
+
+        self.game.stop();
+    }
+}
+
+/// A `struct` that accumulates elapsed time and splits it into fixed steps, as used by
+/// `GameLoop::run_fixed`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use anima_engine::game::FixedTimestep;
+/// let mut t = FixedTimestep::new(Duration::from_millis(10));
+///
+/// t.advance(Duration::from_millis(25));
+///
+/// assert!(t.consume());
+/// assert!(t.consume());
+/// assert!(!t.consume());
+/// assert_eq!(t.alpha(), 0.5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FixedTimestep {
+    /// `Duration` of one step
+    pub step: Duration,
+    /// `Duration` of elapsed time not yet consumed by steps
+    pub accumulator: Duration
+}
+
+impl FixedTimestep {
+    /// Creates a `FixedTimestep` with an empty accumulator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, since `consume` would then never run out of steps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use anima_engine::game::FixedTimestep;
+    /// let t = FixedTimestep::new(Duration::from_millis(10));
+    ///
+    /// assert_eq!(t.accumulator, Duration::from_millis(0));
+    /// ```
+    pub fn new(step: Duration) -> FixedTimestep {
+        assert!(step > Duration::from_millis(0), "FixedTimestep step must be non-zero.");
+
+        FixedTimestep {
+            step: step,
+            accumulator: Duration::from_millis(0)
+        }
+    }
+
+    /// Adds `elapsed` time to the accumulator.
+    pub fn advance(&mut self, elapsed: Duration) {
+        self.accumulator += elapsed;
+    }
+
+    /// Consumes one step from the accumulator, returning whether there was enough time for it.
+    pub fn consume(&mut self) -> bool {
+        if self.accumulator >= self.step {
+            self.accumulator -= self.step;
+
+            true
+        } else {
+            false
         }
     }
+
+    /// Computes the ratio between the time left in the accumulator and one step. This stays
+    /// between `0.0` and `1.0` as long as all full steps have been consumed.
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator.as_secs_f64() / self.step.as_secs_f64()) as f32
+    }
+}
+
+/// A `struct` that averages the last few frame deltas, as used by `GameLoop::new_smoothed`.
+/// Until `window` deltas have been seen, the average is over the ones seen so far.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use anima_engine::game::DeltaSmoother;
+/// let mut s = DeltaSmoother::new(2);
+///
+/// assert_eq!(s.smooth(Duration::from_millis(10)), Duration::from_millis(10));
+/// assert_eq!(s.smooth(Duration::from_millis(20)), Duration::from_millis(15));
+/// assert_eq!(s.smooth(Duration::from_millis(40)), Duration::from_millis(30));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeltaSmoother {
+    window: usize,
+    deltas: VecDeque<Duration>,
+    sum: Duration
+}
+
+impl DeltaSmoother {
+    /// Creates a `DeltaSmoother` averaging over the last `window` deltas. A `window` of `0` is
+    /// treated as `1`, i.e. no smoothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use anima_engine::game::DeltaSmoother;
+    /// let mut s = DeltaSmoother::new(1);
+    ///
+    /// s.smooth(Duration::from_millis(10));
+    ///
+    /// assert_eq!(s.smooth(Duration::from_millis(20)), Duration::from_millis(20));
+    /// ```
+    pub fn new(window: usize) -> DeltaSmoother {
+        let window = window.max(1);
+
+        DeltaSmoother {
+            window: window,
+            deltas: VecDeque::with_capacity(window),
+            sum: Duration::from_millis(0)
+        }
+    }
+
+    /// Adds a raw `delta` to the window and returns the average of the window.
+    pub fn smooth(&mut self, delta: Duration) -> Duration {
+        if self.deltas.len() == self.window {
+            if let Some(oldest) = self.deltas.pop_front() {
+                self.sum -= oldest;
+            }
+        }
+
+        self.deltas.push_back(delta);
+        self.sum += delta;
+
+        self.sum / self.deltas.len() as u32
+    }
 }
@@ -12,5 +12,7 @@ mod game_loop;
 mod mruby_game;
 
 pub use self::game::Game;
+pub use self::game_loop::DeltaSmoother;
+pub use self::game_loop::FixedTimestep;
 pub use self::game_loop::GameLoop;
 pub use self::mruby_game::MrubyGame;
@@ -62,7 +62,7 @@ impl MrubyGame {
 
 impl Game for MrubyGame {
     fn update(&self, dt: Duration) -> bool {
-        let dt = self.mruby.float(dt.as_secs() as f64 + dt.subsec_nanos() as f64 / 1000_000_000.0);
+        let dt = self.mruby.float(dt.as_secs_f64());
 
         self.game.call("update", vec![dt]).unwrap().to_bool().unwrap()
     }
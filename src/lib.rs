@@ -12,4 +12,5 @@ pub extern crate mrusty;
 
 pub mod game;
 pub mod math;
+pub mod scene;
 pub mod scripting;
@@ -0,0 +1,204 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use math::Vector;
+
+/// An axis-aligned bounding box `struct` defined by its minimum and maximum corners.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Aabb;
+/// # use anima_engine::math::Vector;
+/// let a = Aabb::new(Vector::zero(), Vector::one());
+/// let b = Aabb::new(Vector::new_unf(0.5), Vector::new_unf(2.0));
+///
+/// assert!(a.intersects(b));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    /// `Vector` corner with the smallest coordinates
+    pub min: Vector,
+    /// `Vector` corner with the largest coordinates
+    pub max: Vector
+}
+
+impl Aabb {
+    /// Creates a box using two opposite corners, in any order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Aabb;
+    /// # use anima_engine::math::Vector;
+    /// let a = Aabb::new(Vector::new(1.0, 0.0, 1.0), Vector::new(0.0, 1.0, 0.0));
+    ///
+    /// assert_eq!(a, Aabb { min: Vector::zero(), max: Vector::one() });
+    /// ```
+    pub fn new(corner1: Vector, corner2: Vector) -> Aabb {
+        Aabb {
+            min: Vector::new(corner1.x.min(corner2.x), corner1.y.min(corner2.y),
+                             corner1.z.min(corner2.z)),
+            max: Vector::new(corner1.x.max(corner2.x), corner1.y.max(corner2.y),
+                             corner1.z.max(corner2.z))
+        }
+    }
+
+    /// Checks whether a point lies inside a box. Points on the faces are inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Aabb;
+    /// # use anima_engine::math::Vector;
+    /// let a = Aabb::new(Vector::zero(), Vector::one());
+    ///
+    /// assert!(a.contains(Vector::new(1.0, 0.5, 0.0)));
+    /// assert!(!a.contains(Vector::new(1.5, 0.5, 0.0)));
+    /// ```
+    pub fn contains(&self, point: Vector) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x &&
+        point.y >= self.min.y && point.y <= self.max.y &&
+        point.z >= self.min.z && point.z <= self.max.z
+    }
+
+    /// Checks whether two boxes overlap. Boxes touching on a face overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Aabb;
+    /// # use anima_engine::math::Vector;
+    /// let a = Aabb::new(Vector::zero(), Vector::one());
+    /// let b = Aabb::new(Vector::new(1.0, 0.0, 0.0), Vector::new(2.0, 1.0, 1.0));
+    /// let c = Aabb::new(Vector::new(1.5, 0.0, 0.0), Vector::new(2.0, 1.0, 1.0));
+    ///
+    /// assert!(a.intersects(b));
+    /// assert!(!a.intersects(c));
+    /// ```
+    pub fn intersects(&self, other: Aabb) -> bool {
+        self.min.x <= other.max.x && self.max.x >= other.min.x &&
+        self.min.y <= other.max.y && self.max.y >= other.min.y &&
+        self.min.z <= other.max.z && self.max.z >= other.min.z
+    }
+
+    /// Computes the point inside or on the box closest to `point`. Points already inside are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Aabb;
+    /// # use anima_engine::math::Vector;
+    /// let a = Aabb::new(Vector::zero(), Vector::one());
+    ///
+    /// assert_eq!(a.clamp(Vector::new(2.0, -1.0, 3.0)), Vector::new(1.0, 0.0, 1.0));
+    /// assert_eq!(a.clamp(Vector::new(0.5, 2.0, 0.5)), Vector::new(0.5, 1.0, 0.5));
+    /// assert_eq!(a.clamp(Vector::new_unf(0.25)), Vector::new_unf(0.25));
+    /// ```
+    pub fn clamp(&self, point: Vector) -> Vector {
+        Vector {
+            x: point.x.max(self.min.x).min(self.max.x),
+            y: point.y.max(self.min.y).min(self.max.y),
+            z: point.z.max(self.min.z).min(self.max.z)
+        }
+    }
+}
+
+use mrusty::*;
+
+mrusty_class!(Aabb, {
+    def!("initialize", |mruby, corner1: Value, corner2: Value| {
+        let corner1 = try_obj!(mruby, corner1, Vector);
+        let corner2 = try_obj!(mruby, corner2, Vector);
+
+        Aabb::new(corner1, corner2)
+    });
+
+    def!("min", |mruby, slf: Aabb| {
+        mruby.obj(slf.min)
+    });
+
+    def!("max", |mruby, slf: Aabb| {
+        mruby.obj(slf.max)
+    });
+
+    def!("==", |mruby, slf: Aabb, other: Value| {
+        let other = try_obj!(other, Aabb, else mruby.bool(false));
+
+        let result = slf.min == other.min &&
+                     slf.max == other.max;
+
+        mruby.bool(result)
+    });
+
+    def!("to_s", |mruby, slf: Aabb| {
+        let string = format!("<Aabb: @min=<Vector: @x={} @y={} @z={}> \
+                              @max=<Vector: @x={} @y={} @z={}>>",
+                             slf.min.x, slf.min.y, slf.min.z, slf.max.x, slf.max.y, slf.max.z);
+
+        mruby.string(&string)
+    });
+
+    def!("contains?", |mruby, slf: Aabb, point: Value| {
+        let point = try_obj!(mruby, point, Vector);
+
+        mruby.bool(slf.contains(point))
+    });
+
+    def!("intersects?", |mruby, slf: Aabb, other: Value| {
+        let other = try_obj!(mruby, other, Aabb);
+
+        mruby.bool(slf.intersects(other))
+    });
+
+    def!("clamp", |mruby, slf: Aabb, point: Value| {
+        let point = try_obj!(mruby, point, Vector);
+
+        mruby.obj(slf.clamp(point))
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::Aabb;
+    use super::super::Vector;
+
+    describe!(Aabb, (Vector), "
+      context 'when unit' do
+        subject { Aabb.new Vector.one, Vector.zero }
+
+        it 'returns min on #min' do
+          expect(subject.min).to eql Vector.zero
+        end
+
+        it 'returns max on #max' do
+          expect(subject.max).to eql Vector.one
+        end
+
+        it 'contains inner points on #contains?' do
+          expect(subject.contains? Vector.uniform(0.5)).to be true
+          expect(subject.contains? Vector.uniform(1.5)).to be false
+        end
+
+        it 'intersects overlapping boxes on #intersects?' do
+          expect(subject.intersects? Aabb.new(Vector.uniform(0.5), Vector.uniform(2.0))).to be true
+          expect(subject.intersects? Aabb.new(Vector.uniform(1.5), Vector.uniform(2.0))).to be false
+        end
+
+        it 'clamps outer points to the nearest corner on #clamp' do
+          expect(subject.clamp Vector.uniform(2.0)).to eql Vector.one
+        end
+
+        it 'leaves inner points unchanged on #clamp' do
+          expect(subject.clamp Vector.uniform(0.5)).to eql Vector.uniform(0.5)
+        end
+      end
+    ");
+}
@@ -5,7 +5,9 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use math::Vector;
+use std::slice;
+
+use math::{Curve, Plane, Vector};
 
 /// A `macro` useful for defining Bézier curves.
 ///
@@ -142,6 +144,33 @@ impl Bezier {
         }
     }
 
+    /// Creates a cubic Bézier equivalent to the cubic Hermite curve from `p0` to `p1` with
+    /// tangents `t0` and `t1` at its ends, i.e. with control points `p0 + t0 / 3` and
+    /// `p1 - t1 / 3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b = Bezier::from_hermite(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(0.0, 3.0, 0.0),
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Vector::new(0.0, -3.0, 0.0)
+    /// );
+    ///
+    /// assert_eq!(b, Bezier::new_cub(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(0.0, 1.0, 0.0),
+    ///     Vector::new(1.0, 1.0, 0.0),
+    ///     Vector::new(1.0, 0.0, 0.0)
+    /// ));
+    /// ```
+    pub fn from_hermite(p0: Vector, t0: Vector, p1: Vector, t1: Vector) -> Bezier {
+        Bezier::new_cub(p0, p0 + t0 * (1.0 / 3.0), p1 - t1 * (1.0 / 3.0), p1)
+    }
+
     /// Computes the vector on a Bézier curve correspoding to a `ratio` (between `0.0` and `1.0`).
     ///
     /// # Examples
@@ -186,14 +215,111 @@ impl Bezier {
         }
     }
 
-    /// Computes the approximated length of a Bézier curve by summing the distances between `steps`
-    /// uniformly distrubuted, consecutive points.
+    /// Computes the normalized tangent of a Bézier curve correspoding to a `ratio` (between
+    /// `0.0` and `1.0`), i.e. its direction of travel. Where the derivative vanishes (e.g. at an
+    /// end whose control point coincides with it) the direction of the chord is used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 1.0, 0.0),
+    ///     Vector::new(2.0, 0.0, 0.0)
+    /// );
+    ///
+    /// assert_eq!(b.tangent(0.5), Vector::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn tangent(&self, ratio: f32) -> Vector {
+        let derivative = match self.v4 {
+            Some(v4) => {
+                (self.v2 - self.v1) * 3.0 * (1.0 - ratio).powi(2) +
+                (self.v3 - self.v2) * 6.0 * (1.0 - ratio) * ratio +
+                (v4 - self.v3) * 3.0 * ratio.powi(2)
+            },
+            None => {
+                (self.v2 - self.v1) * 2.0 * (1.0 - ratio) +
+                (self.v3 - self.v2) * 2.0 * ratio
+            }
+        };
+
+        derivative.normalize_or((self.end() - self.v1).norm())
+    }
+
+    /// Computes both the vector and the normalized tangent of a Bézier curve correspoding to a
+    /// `ratio` (between `0.0` and `1.0`) in one pass, sharing the terms common to `interpolate`
+    /// and `tangent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 1.0, 0.0),
+    ///     Vector::new(2.0, 0.0, 0.0)
+    /// );
+    ///
+    /// let (point, tangent) = b.point_and_tangent(0.5);
+    ///
+    /// assert_eq!(point, Vector::new(1.0, 0.5, 0.0));
+    /// assert_eq!(tangent, Vector::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn point_and_tangent(&self, ratio: f32) -> (Vector, Vector) {
+        let inv = 1.0 - ratio;
+        let inv2 = inv * inv;
+        let ratio2 = ratio * ratio;
+
+        let (point, derivative) = match self.v4 {
+            Some(v4) => {
+                let point = self.v1 * inv2 * inv +
+                            self.v2 * 3.0 * inv2 * ratio +
+                            self.v3 * 3.0 * inv * ratio2 +
+                            v4 * ratio2 * ratio;
+                let derivative = (self.v2 - self.v1) * 3.0 * inv2 +
+                                 (self.v3 - self.v2) * 6.0 * inv * ratio +
+                                 (v4 - self.v3) * 3.0 * ratio2;
+
+                (point, derivative)
+            },
+            None => {
+                let point = self.v1 * inv2 +
+                            self.v2 * 2.0 * inv * ratio +
+                            self.v3 * ratio2;
+                let derivative = (self.v2 - self.v1) * 2.0 * inv +
+                                 (self.v3 - self.v2) * 2.0 * ratio;
+
+                (point, derivative)
+            }
+        };
+
+        (point, derivative.normalize_or((self.end() - self.v1).norm()))
+    }
+
+    /// Computes the length of a Bézier curve. Square curves (including straight ones) have an
+    /// exact, closed-form length and ignore `steps`. Cubic curves are approximated by summing the
+    /// distances between `steps` uniformly distrubuted, consecutive points.
     ///
     /// # Examples
     ///
     /// ```
     /// # use anima_engine::math::Bezier;
     /// # use anima_engine::math::Vector;
+    /// let b = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Vector::new(2.0, 0.0, 0.0)
+    /// );
+    ///
+    /// assert_eq!(b.len(1), 2.0);
+    /// ```
+    ///
+    /// ```
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
     /// # use std::f32::consts;
     /// // approximation of radius 1.0 circle arc
     /// let b = Bezier::new_cub(
@@ -208,6 +334,10 @@ impl Bezier {
     /// assert!((b.len(20) - consts::PI / 2.0).abs() < EPSILON);
     /// ```
     pub fn len(&self, steps: i32) -> f32 {
+        if self.v4.is_none() {
+            return self.sqr_len();
+        }
+
         let (length, _) = (1..steps + 1).fold((0.0, self.v1), |(l, v), i| {
             let n = self.interpolate((i as f32) / (steps as f32));
 
@@ -216,55 +346,477 @@ impl Bezier {
 
         length
     }
+
+    /// Computes the cubic Bézier tracing the same curve as a square one by degree elevation.
+    /// Cubic Béziers are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(3.0, 3.0, 0.0),
+    ///     Vector::new(6.0, 0.0, 0.0)
+    /// );
+    ///
+    /// assert_eq!(b.elevate(), Bezier::new_cub(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(2.0, 2.0, 0.0),
+    ///     Vector::new(4.0, 2.0, 0.0),
+    ///     Vector::new(6.0, 0.0, 0.0)
+    /// ));
+    /// ```
+    pub fn elevate(&self) -> Bezier {
+        match self.v4 {
+            Some(_) => *self,
+            None    => {
+                Bezier::new_cub(
+                    self.v1,
+                    self.v1 * (1.0 / 3.0) + self.v2 * (2.0 / 3.0),
+                    self.v2 * (2.0 / 3.0) + self.v3 * (1.0 / 3.0),
+                    self.v3
+                )
+            }
+        }
+    }
+
+    /// Reverses the direction of a Bézier curve, so that `reversed.interpolate(ratio)` equals
+    /// `interpolate(1.0 - ratio)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 1.0, 0.0),
+    ///     Vector::new(2.0, 0.0, 0.0)
+    /// );
+    ///
+    /// assert_eq!(b.reverse(), Bezier::new_sqr(
+    ///     Vector::new(2.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 1.0, 0.0),
+    ///     Vector::new(0.0, 0.0, 0.0)
+    /// ));
+    /// ```
+    pub fn reverse(&self) -> Bezier {
+        match self.v4 {
+            Some(v4) => Bezier::new_cub(v4, self.v3, self.v2, self.v1),
+            None     => Bezier::new_sqr(self.v3, self.v2, self.v1)
+        }
+    }
+
+    /// Approximates a Bézier curve with a polyline by recursively splitting it in half (de
+    /// Casteljau) until every piece is flat enough. The returned points include both ends.
+    ///
+    /// A piece's flatness is the largest distance from its inner control points to the segment
+    /// joining its ends. Since the curve lies inside the convex hull of its control points, no
+    /// point of a piece strays farther than this from its segment, so `tolerance` bounds the
+    /// error of the whole polyline. Recursion depth is capped, so a `tolerance` of `0.0` yields
+    /// a finite (but large) number of points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Vector::new(2.0, 0.0, 0.0)
+    /// );
+    ///
+    /// assert_eq!(b.flatten(0.01), vec![Vector::new(0.0, 0.0, 0.0), Vector::new(2.0, 0.0, 0.0)]);
+    /// ```
+    pub fn flatten(&self, tolerance: f32) -> Vec<Vector> {
+        const MAX_DEPTH: u32 = 16;
+
+        let mut points = vec![self.v1];
+
+        self.flatten_into(tolerance, MAX_DEPTH, &mut points);
+
+        points
+    }
+
+    /// Approximates the curve parallel to a Bézier curve at `distance`, offset in the *xy* plane
+    /// along the tangent rotated by 90° around *z*. (to the left of the direction of travel for
+    /// positive distances)
+    ///
+    /// Offsets of Bézier curves are not polynomial, so they cannot be represented exactly. The
+    /// curve is instead sampled at `steps` uniformly distributed intervals, every sample is
+    /// offset along its normal and consecutive samples are joined by straight segments into a
+    /// polyline-backed `BezierPath`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Vector::new(2.0, 0.0, 0.0)
+    /// );
+    ///
+    /// let p = b.offset(1.0, 4);
+    ///
+    /// assert_eq!(p.curves.len(), 4);
+    /// assert_eq!(p.interpolate(0.5), Vector::new(1.0, 1.0, 0.0));
+    /// ```
+    pub fn offset(&self, distance: f32, steps: i32) -> BezierPath {
+        let steps = steps.max(1);
+
+        let points: Vec<Vector> = (0..steps + 1).map(|i| {
+            let ratio = i as f32 / steps as f32;
+            let tangent = self.tangent(ratio);
+            let normal = Vector::new(-tangent.y, tangent.x, 0.0).normalize_or(Vector::zero());
+
+            self.interpolate(ratio) + normal * distance
+        }).collect();
+
+        let curves = points.windows(2).map(|pair| {
+            Bezier::new_sqr(pair[0], (pair[0] + pair[1]) * 0.5, pair[1])
+        }).collect();
+
+        BezierPath::new(curves)
+    }
+
+    /// Finds the point on a Bézier curve closest to `point` and its `ratio`. The curve is sampled
+    /// at `steps + 1` uniformly distributed ratios, so the returned `ratio` is a multiple of
+    /// `1.0 / steps`; increase `steps` for more precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Vector::new(2.0, 0.0, 0.0)
+    /// );
+    ///
+    /// let (ratio, closest) = b.closest_point(Vector::new(0.5, 1.0, 0.0), 4);
+    ///
+    /// assert_eq!(ratio, 0.25);
+    /// assert_eq!(closest, Vector::new(0.5, 0.0, 0.0));
+    /// ```
+    pub fn closest_point(&self, point: Vector, steps: i32) -> (f32, Vector) {
+        let steps = steps.max(1);
+
+        (0..steps + 1).map(|i| {
+            let ratio = i as f32 / steps as f32;
+
+            (ratio, self.interpolate(ratio))
+        }).fold((0.0, self.v1), |closest, candidate| {
+            if candidate.1.dist(point) < closest.1.dist(point) {
+                candidate
+            } else {
+                closest
+            }
+        })
+    }
+
+    /// Finds the ratios where the curve crosses a `plane`, in increasing order. The curve is
+    /// sampled `steps` times; sign changes of the signed distance between consecutive samples
+    /// are then refined with bisection. Samples lying exactly on the plane are returned as they
+    /// are.
+    ///
+    /// Crossings closer together than one step (`1 / steps`) may cancel out and be missed, as
+    /// may tangential touches, so `steps` should grow with how wavy the curve is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Plane;
+    /// # use anima_engine::math::Vector;
+    /// let b = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Vector::new(2.0, 0.0, 0.0)
+    /// );
+    ///
+    /// let ratios = b.intersect_plane(&Plane::new(Vector::left(), -0.5), 10);
+    ///
+    /// assert_eq!(ratios.len(), 1);
+    /// assert!((ratios[0] - 0.25).abs() < 0.00001);
+    /// ```
+    pub fn intersect_plane(&self, plane: &Plane, steps: i32) -> Vec<f32> {
+        const BISECTIONS: i32 = 24;
+
+        let steps = steps.max(1);
+        let dist = |ratio: f32| plane.signed_dist(self.interpolate(ratio));
+
+        let mut ratios = Vec::new();
+        let mut start = 0.0;
+        let mut start_dist = dist(start);
+
+        if start_dist == 0.0 {
+            ratios.push(start);
+        }
+
+        for i in 1..steps + 1 {
+            let end = i as f32 / steps as f32;
+            let end_dist = dist(end);
+
+            if end_dist == 0.0 {
+                ratios.push(end);
+            } else if start_dist * end_dist < 0.0 {
+                let (mut low, mut high) = (start, end);
+                let mut low_dist = start_dist;
+
+                for _ in 0..BISECTIONS {
+                    let mid = (low + high) / 2.0;
+                    let mid_dist = dist(mid);
+
+                    if mid_dist * low_dist > 0.0 {
+                        low = mid;
+                        low_dist = mid_dist;
+                    } else {
+                        high = mid;
+                    }
+                }
+
+                ratios.push((low + high) / 2.0);
+            }
+
+            start = end;
+            start_dist = end_dist;
+        }
+
+        ratios
+    }
+
+    // Integrates the speed of a square curve, |2at + b|, in closed form. Computed in f64 since
+    // the general formula suffers from cancellation.
+    fn sqr_len(&self) -> f32 {
+        let a = self.v1 - self.v2 * 2.0 + self.v3;
+        let b = (self.v2 - self.v1) * 2.0;
+
+        let qa = 4.0 * a.dot(a) as f64;
+        let qb = 4.0 * a.dot(b) as f64;
+        let qc = b.dot(b) as f64;
+
+        // straight line traveled at constant speed
+        if qa <= 1e-12 {
+            return qc.sqrt() as f32;
+        }
+
+        let disc = 4.0 * qa * qc - qb * qb;
+
+        // straight line whose speed is |t - root| scaled, possibly turning back
+        if disc <= 1e-9 * qa * qc {
+            let root = -qb / (2.0 * qa);
+            let integral = if root <= 0.0 {
+                0.5 - root
+            } else if root >= 1.0 {
+                root - 0.5
+            } else {
+                (root * root + (1.0 - root) * (1.0 - root)) / 2.0
+            };
+
+            return (qa.sqrt() * integral) as f32;
+        }
+
+        let sabc = 2.0 * (qa + qb + qc).sqrt();
+        let a2 = qa.sqrt();
+        let a32 = 2.0 * qa * a2;
+        let c2 = 2.0 * qc.sqrt();
+        let ba = qb / a2;
+
+        let length = (a32 * sabc + a2 * qb * (sabc - c2) +
+                      disc * ((2.0 * a2 + ba + sabc) / (ba + c2)).ln()) / (4.0 * a32);
+
+        length as f32
+    }
+
+    fn flatten_into(&self, tolerance: f32, depth: u32, points: &mut Vec<Vector>) {
+        if depth == 0 || self.flatness() <= tolerance {
+            points.push(self.end());
+        } else {
+            let (left, right) = self.split();
+
+            left.flatten_into(tolerance, depth - 1, points);
+            right.flatten_into(tolerance, depth - 1, points);
+        }
+    }
+
+    fn flatness(&self) -> f32 {
+        let end = self.end();
+        let dist = |point: Vector| {
+            let chord = end - self.v1;
+            let len = chord.dot(chord);
+
+            if len == 0.0 {
+                point.dist(self.v1)
+            } else {
+                let ratio = ((point - self.v1).dot(chord) / len).max(0.0).min(1.0);
+
+                point.dist(self.v1 + chord * ratio)
+            }
+        };
+
+        match self.v4 {
+            Some(_) => dist(self.v2).max(dist(self.v3)),
+            None    => dist(self.v2)
+        }
+    }
+
+    fn split(&self) -> (Bezier, Bezier) {
+        let mid = |v1: Vector, v2: Vector| (v1 + v2) * 0.5;
+
+        match self.v4 {
+            Some(v4) => {
+                let v12 = mid(self.v1, self.v2);
+                let v23 = mid(self.v2, self.v3);
+                let v34 = mid(self.v3, v4);
+                let v123 = mid(v12, v23);
+                let v234 = mid(v23, v34);
+                let m = mid(v123, v234);
+
+                (Bezier::new_cub(self.v1, v12, v123, m), Bezier::new_cub(m, v234, v34, v4))
+            }
+            None => {
+                let v12 = mid(self.v1, self.v2);
+                let v23 = mid(self.v2, self.v3);
+                let m = mid(v12, v23);
+
+                (Bezier::new_sqr(self.v1, v12, m), Bezier::new_sqr(m, v23, self.v3))
+            }
+        }
+    }
+
+    fn end(&self) -> Vector {
+        self.v4.unwrap_or(self.v3)
+    }
+}
+
+impl Curve for Bezier {
+    fn interpolate(&self, ratio: f32) -> Vector {
+        Bezier::interpolate(self, ratio)
+    }
+
+    fn len(&self, steps: i32) -> f32 {
+        Bezier::len(self, steps)
+    }
+
+    fn tangent(&self, ratio: f32) -> Vector {
+        Bezier::tangent(self, ratio)
+    }
 }
 
 use mrusty::*;
 
 mrusty_class!(Bezier, {
     def!("initialize", |mruby; args| {
-        match args.len() {
-            3 => {
-                Bezier::new_sqr(
-                    (*args[0].to_obj::<Vector>().unwrap()).clone(),
-                    (*args[1].to_obj::<Vector>().unwrap()).clone(),
-                    (*args[2].to_obj::<Vector>().unwrap()).clone()
-                )
-            }
-            4 => {
-                Bezier::new_cub(
-                    (*args[0].to_obj::<Vector>().unwrap()).clone(),
-                    (*args[1].to_obj::<Vector>().unwrap()).clone(),
-                    (*args[2].to_obj::<Vector>().unwrap()).clone(),
-                    (*args[3].to_obj::<Vector>().unwrap()).clone()
-                )
-            }
+        let vectors: Result<Vec<Vector>, _> = args.iter().map(|arg| {
+            arg.to_obj::<Vector>().map(|vector| (*vector).clone())
+        }).collect();
+
+        let vectors = match vectors {
+            Ok(vectors) => vectors,
+            Err(_)      => return mruby.raise("TypeError", "expecting Vector")
+        };
+
+        match vectors.len() {
+            3 => Bezier::new_sqr(vectors[0], vectors[1], vectors[2]),
+            4 => Bezier::new_cub(vectors[0], vectors[1], vectors[2], vectors[3]),
             _ => {
                 return mruby.raise("ArgumentError", "wrong number of arguments")
             }
         }
     });
 
+    def_self!("from_hermite", |mruby, _slf: Value, p0: Value, t0: Value, p1: Value, t1: Value| {
+        let p0 = try_obj!(mruby, p0, Vector);
+        let t0 = try_obj!(mruby, t0, Vector);
+        let p1 = try_obj!(mruby, p1, Vector);
+        let t1 = try_obj!(mruby, t1, Vector);
+
+        let bezier = Bezier::from_hermite(p0, t0, p1, t1);
+
+        mruby.obj(bezier)
+    });
+
     def!("interpolate", |mruby, slf: Bezier, ratio: f64| {
         mruby.obj(slf.interpolate(ratio as f32))
     });
 
+    def!("point_and_tangent", |mruby, slf: Bezier, ratio: f64| {
+        let (point, tangent) = slf.point_and_tangent(ratio as f32);
+
+        mruby.array(vec![mruby.obj(point), mruby.obj(tangent)])
+    });
+
     def!("length", |mruby, slf: Bezier; args| {
         match args.len() {
             0 => mruby.float(slf.len(20) as f64),
-            1 => mruby.float(slf.len(args[0].to_i32().unwrap()) as f64),
+            1 => match args[0].to_i32() {
+                Ok(steps) => mruby.float(slf.len(steps) as f64),
+                Err(_)    => mruby.raise("TypeError", "expecting Fixnum")
+            },
             _ => mruby.raise("ArgumentError", "wrong number of arguments")
         }
     });
+
+    def!("elevate", |mruby, slf: Bezier| {
+        mruby.obj(slf.elevate())
+    });
+
+    def!("tangent", |mruby, slf: Bezier, ratio: f64| {
+        mruby.obj(slf.tangent(ratio as f32))
+    });
+
+    def!("closest_point", |mruby, slf: Bezier, point: Value, steps: i32| {
+        let point = try_obj!(mruby, point, Vector);
+
+        let (ratio, closest) = slf.closest_point(point, steps);
+
+        mruby.array(vec![mruby.float(ratio as f64), mruby.obj(closest)])
+    });
+
+    def!("intersect_plane", |mruby, slf: Bezier, plane: Value, steps: i32| {
+        let plane = try_obj!(mruby, plane, Plane);
+
+        let ratios = slf.intersect_plane(&plane, steps).into_iter().map(|ratio| {
+            mruby.float(ratio as f64)
+        }).collect();
+
+        mruby.array(ratios)
+    });
+
+    def!("offset", |mruby, slf: Bezier, distance: f64, steps: i32| {
+        mruby.obj(slf.offset(distance as f32, steps))
+    });
+
+    def!("flatten", |mruby, slf: Bezier, tolerance: f64| {
+        let points = slf.flatten(tolerance as f32).into_iter().map(|p| mruby.obj(p)).collect();
+
+        mruby.array(points)
+    });
 });
 
+const PATH_STEPS: i32 = 20;
+
 /// A `struct` useful for creating a path of Bézier curves.
+///
+/// Curve lengths are approximated once, on creation, with a resolution of `steps` per curve
+/// (20 by default) and are then reused by `interpolate` and `len`.
 #[derive(Clone, Debug, PartialEq)]
 pub struct BezierPath {
     /// `Vec<Bezier>` of curves forming the path
     pub curves: Vec<Bezier>,
     /// `Vec<f32>` containing the lengths of the `Bezier` curves with the same indices;
     /// (normalized so that they add up to `1.0`)
-    pub lengths: Vec<f32>
+    pub lengths: Vec<f32>,
+    /// `f32` approximated length of the whole path (`steps` per curve)
+    pub length: f32,
+    /// `i32` number of steps per curve used to approximate lengths
+    pub steps: i32
 }
 
 impl BezierPath {
@@ -288,18 +840,47 @@ impl BezierPath {
     ///         Vector::new(1.0, 0.0, 0.0),
     ///         Vector::new(2.0, 0.0, 0.0)
     ///     )),
-    ///     lengths: vec!(1.0)
+    ///     lengths: vec!(1.0),
+    ///     length: 2.0,
+    ///     steps: 20
     /// });
     /// ```
     pub fn new(curves: Vec<Bezier>) -> BezierPath {
-        const STEPS: i32 = 20;
+        BezierPath::new_with_steps(curves, PATH_STEPS)
+    }
 
-        let lengths: Vec<f32> = curves.iter().map(|c| c.len(STEPS)).collect();
+    /// Creates a Bézier path using `Bezier` curves, approximating curve lengths with `steps` per
+    /// curve. Curves must be connected.
+    ///
+    /// Lengths are only computed once, on creation, but every step samples the curve. More steps
+    /// give more accurate lengths, which makes `interpolate` move at a more even speed across
+    /// curves; fewer steps are cheaper and good enough for short or nearly straight curves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::BezierPath;
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Vector::new(2.0, 0.0, 0.0)
+    /// );
+    /// let p = BezierPath::new_with_steps(vec![b], 5);
+    ///
+    /// assert_eq!(p.steps, 5);
+    /// assert_eq!(p.len(5), p.length);
+    /// ```
+    pub fn new_with_steps(curves: Vec<Bezier>, steps: i32) -> BezierPath {
+        let lengths: Vec<f32> = curves.iter().map(|c| c.len(steps)).collect();
         let sum = lengths.iter().fold(0.0, |s, l| s + l);
 
         BezierPath {
             curves: curves,
-            lengths: lengths.iter().map(|l| l / sum).collect()
+            lengths: lengths.iter().map(|l| l / sum).collect(),
+            length: sum,
+            steps: steps
         }
     }
 
@@ -327,38 +908,44 @@ impl BezierPath {
     /// assert_eq!(p.interpolate(1.2), Vector::new(12.0, 12.0, 0.0));
     /// ```
     pub fn interpolate(&self, ratio: f32) -> Vector {
-        let mut sum = 0.0;
-
-        let curve_length = self.curves.iter().zip(self.lengths.iter()).find(|&(_, l)| {
-            if ratio <= sum + l {
-                true
-            } else {
-                sum = sum + l;
-
-                false
-            }
-        });
+        let (curve, ratio) = self.locate(ratio);
 
-        let (curve, ratio) = match curve_length {
-            Some((curve, length)) => (curve, (ratio - sum) / length),
-            None                  => {
-                let curve = self.curves.last();
-                let length = self.lengths.last();
+        curve.interpolate(ratio)
+    }
 
-                match (curve, length) {
-                    (Some(curve), Some(length)) => {
-                        (curve, (ratio - sum + length) / length)
-                    },
-                    _ => panic!("Cannot interpolate an empty path.")
-                }
-            }
-        };
+    /// Computes the normalized tangent of a Bézier path correspoding to a `ratio` (between `0.0`
+    /// and `1.0`), i.e. the tangent of the curve containing `ratio`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::BezierPath;
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b1 = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Vector::new(2.0, 0.0, 0.0)
+    /// );
+    /// let b2 = Bezier::new_sqr(
+    ///     Vector::new(2.0, 0.0, 0.0),
+    ///     Vector::new(2.0, 1.0, 0.0),
+    ///     Vector::new(2.0, 2.0, 0.0)
+    /// );
+    /// let p = BezierPath::new(vec![b1, b2]);
+    ///
+    /// assert_eq!(p.tangent(0.25), Vector::new(1.0, 0.0, 0.0));
+    /// assert_eq!(p.tangent(0.75), Vector::new(0.0, 1.0, 0.0));
+    /// ```
+    pub fn tangent(&self, ratio: f32) -> Vector {
+        let (curve, ratio) = self.locate(ratio);
 
-        curve.interpolate(ratio)
+        curve.tangent(ratio)
     }
 
     /// Computes the approximated length of a Bézier path by summing the distances between `steps`
-    /// uniformly distrubuted, consecutive points per curve.
+    /// uniformly distrubuted, consecutive points per curve. For the path's own `steps` the length
+    /// cached on creation is returned without sampling the curves again.
     ///
     /// # Examples
     ///
@@ -383,8 +970,176 @@ impl BezierPath {
     /// assert!((p.len(20) - 14.142137).abs() < EPSILON);
     /// ```
     pub fn len(&self, steps: i32) -> f32 {
+        if steps == self.steps {
+            return self.length;
+        }
+
         self.curves.iter().map(|curve| curve.len(steps)).fold(0.0, |s, l| s + l)
     }
+
+    /// Iterates over the curves of a Bézier path paired with their lengths, normalized to the
+    /// length of the whole path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::BezierPath;
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b1 = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Vector::new(2.0, 0.0, 0.0)
+    /// );
+    /// let b2 = Bezier::new_sqr(
+    ///     Vector::new(2.0, 0.0, 0.0),
+    ///     Vector::new(2.5, 0.0, 0.0),
+    ///     Vector::new(3.0, 0.0, 0.0)
+    /// );
+    /// let p = BezierPath::new(vec![b1, b2]);
+    ///
+    /// let lengths: Vec<f32> = p.segments().map(|(_, l)| l).collect();
+    ///
+    /// assert_eq!(lengths.len(), 2);
+    /// assert!(lengths[0] > lengths[1]);
+    /// ```
+    pub fn segments<'a>(&'a self) -> impl Iterator<Item = (&'a Bezier, f32)> + 'a {
+        self.curves.iter().zip(self.lengths.iter().cloned())
+    }
+
+    /// Finds the point on a Bézier path closest to `point` and its path `ratio`. Every curve is
+    /// sampled like `Bezier::closest_point` with `steps` steps, so the precision is relative to
+    /// each curve rather than to the whole path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::BezierPath;
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b1 = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Vector::new(2.0, 0.0, 0.0)
+    /// );
+    /// let b2 = Bezier::new_sqr(
+    ///     Vector::new(2.0, 0.0, 0.0),
+    ///     Vector::new(2.0, 1.0, 0.0),
+    ///     Vector::new(2.0, 2.0, 0.0)
+    /// );
+    /// let p = BezierPath::new(vec![b1, b2]);
+    ///
+    /// let (ratio, closest) = p.closest_point(Vector::new(1.0, -1.0, 0.0), 10);
+    ///
+    /// assert_eq!(ratio, 0.25);
+    /// assert_eq!(closest, Vector::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn closest_point(&self, point: Vector, steps: i32) -> (f32, Vector) {
+        let mut sum = 0.0;
+        let mut closest: Option<(f32, Vector)> = None;
+
+        for (curve, length) in self.segments() {
+            let (ratio, candidate) = curve.closest_point(point, steps);
+            let candidate = (sum + ratio * length, candidate);
+
+            closest = match closest {
+                Some(closest) if closest.1.dist(point) <= candidate.1.dist(point) => Some(closest),
+                _ => Some(candidate)
+            };
+
+            sum += length;
+        }
+
+        closest.expect("Cannot find closest point on an empty path.")
+    }
+
+    /// Reverses the direction of a Bézier path, e.g. to traverse it backwards, so that
+    /// `reversed.interpolate(ratio)` equals `interpolate(1.0 - ratio)`. Cached lengths are
+    /// reordered rather than recomputed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::BezierPath;
+    /// # use anima_engine::math::Bezier;
+    /// # use anima_engine::math::Vector;
+    /// let b1 = Bezier::new_sqr(
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Vector::new(1.0, 1.0, 0.0),
+    ///     Vector::new(2.0, 2.0, 0.0)
+    /// );
+    /// let b2 = Bezier::new_sqr(
+    ///     Vector::new(2.0, 2.0, 0.0),
+    ///     Vector::new(6.0, 6.0, 0.0),
+    ///     Vector::new(10.0, 10.0, 0.0)
+    /// );
+    /// let p = BezierPath::new(vec![b1, b2]).reverse();
+    ///
+    /// assert_eq!(p.interpolate(0.0), Vector::new(10.0, 10.0, 0.0));
+    /// assert_eq!(p.interpolate(0.5), Vector::new(5.0, 5.0, 0.0));
+    /// assert_eq!(p.lengths, vec![0.8, 0.2]);
+    /// ```
+    pub fn reverse(&self) -> BezierPath {
+        BezierPath {
+            curves: self.curves.iter().rev().map(|c| c.reverse()).collect(),
+            lengths: self.lengths.iter().rev().cloned().collect(),
+            length: self.length,
+            steps: self.steps
+        }
+    }
+
+    // Finds the curve containing a path `ratio` and the `ratio` local to that curve.
+    fn locate(&self, ratio: f32) -> (&Bezier, f32) {
+        let mut sum = 0.0;
+
+        let curve_length = self.curves.iter().zip(self.lengths.iter()).find(|&(_, l)| {
+            if ratio <= sum + l {
+                true
+            } else {
+                sum = sum + l;
+
+                false
+            }
+        });
+
+        match curve_length {
+            Some((curve, length)) => (curve, (ratio - sum) / length),
+            None                  => {
+                let curve = self.curves.last();
+                let length = self.lengths.last();
+
+                match (curve, length) {
+                    (Some(curve), Some(length)) => {
+                        (curve, (ratio - sum + length) / length)
+                    },
+                    _ => panic!("Cannot interpolate an empty path.")
+                }
+            }
+        }
+    }
+}
+
+impl Curve for BezierPath {
+    fn interpolate(&self, ratio: f32) -> Vector {
+        BezierPath::interpolate(self, ratio)
+    }
+
+    fn len(&self, steps: i32) -> f32 {
+        BezierPath::len(self, steps)
+    }
+
+    fn tangent(&self, ratio: f32) -> Vector {
+        BezierPath::tangent(self, ratio)
+    }
+}
+
+impl<'a> IntoIterator for &'a BezierPath {
+    type Item = &'a Bezier;
+    type IntoIter = slice::Iter<'a, Bezier>;
+
+    fn into_iter(self) -> slice::Iter<'a, Bezier> {
+        self.curves.iter()
+    }
 }
 
 mrusty_class!(BezierPath, {
@@ -401,11 +1156,17 @@ mrusty_class!(BezierPath, {
                             "Array"  => {
                                 let array = value.to_vec().unwrap();
 
-                                let x = array[0].to_f64().unwrap();
-                                let y = array[1].to_f64().unwrap();
-                                let z = array[2].to_f64().unwrap();
+                                if array.len() != 3 {
+                                    return Err(mruby.raise("ArgumentError",
+                                                           "Array should contain 3 Floats"));
+                                }
 
-                                Ok(Vector::new(x as f32, y as f32, z as f32))
+                                match (array[0].to_f64(), array[1].to_f64(), array[2].to_f64()) {
+                                    (Ok(x), Ok(y), Ok(z)) => {
+                                        Ok(Vector::new(x as f32, y as f32, z as f32))
+                                    }
+                                    _ => Err(mruby.raise("TypeError", "expecting Array of Floats"))
+                                }
                             }
                             _ => Err(mruby.raise("ArgumentError",
                                                  "Array should contain Vector or Array"))
@@ -485,10 +1246,25 @@ mrusty_class!(BezierPath, {
         mruby.obj(slf.interpolate(ratio as f32))
     });
 
+    def!("reverse", |mruby, slf: BezierPath| {
+        mruby.obj(slf.reverse())
+    });
+
+    def!("closest_point", |mruby, slf: BezierPath, point: Value, steps: i32| {
+        let point = try_obj!(mruby, point, Vector);
+
+        let (ratio, closest) = slf.closest_point(point, steps);
+
+        mruby.array(vec![mruby.float(ratio as f64), mruby.obj(closest)])
+    });
+
     def!("length", |mruby, slf: BezierPath; args| {
         match args.len() {
-            0 => mruby.float(slf.len(20) as f64),
-            1 => mruby.float(slf.len(args[0].to_i32().unwrap()) as f64),
+            0 => mruby.float(slf.length as f64),
+            1 => match args[0].to_i32() {
+                Ok(steps) => mruby.float(slf.len(steps) as f64),
+                Err(_)    => mruby.raise("TypeError", "expecting Fixnum")
+            },
             _ => mruby.raise("ArgumentError", "wrong number of arguments")
         }
     });
@@ -499,9 +1275,28 @@ mod test_bezier {
     use mrusty::*;
 
     use super::Bezier;
-    use super::super::Vector;
+    use super::BezierPath;
+    use super::super::{Plane, Vector};
+
+    describe!(Bezier, (BezierPath, Plane, Vector), "
+      context 'when passed wrong types' do
+        it 'raises TypeError on .new' do
+          expect { Bezier.new Vector.zero, [1.0, 1.0, 1.0], Vector.one }.to raise_error TypeError
+        end
+
+        it 'raises TypeError on .from_hermite' do
+          expect { Bezier.from_hermite Vector.zero, 1.0, Vector.one, Vector.one }.to raise_error(
+            TypeError
+          )
+        end
+
+        it 'raises TypeError on #closest_point' do
+          bezier = Bezier.new Vector.zero, Vector.one, Vector.uniform(2.0)
+
+          expect { bezier.closest_point [1.0, 1.0, 1.0], 10 }.to raise_error TypeError
+        end
+      end
 
-    describe!(Bezier, (Vector), "
       context 'when square arc' do
         subject { Bezier.new Vector.forward, Vector.uniform(1.0), Vector.left }
 
@@ -509,12 +1304,57 @@ mod test_bezier {
           expect(subject.interpolate 0.5).to eql Vector.new(0.75, 0.5, 0.75)
         end
 
-        it 'returns approximated length on #length' do
-          expect(subject.length).to be_within(0.000001).of 1.950975
+        it 'returns point and tangent on #point_and_tangent' do
+          point, tangent = subject.point_and_tangent 0.5
+
+          expect(point).to eql subject.interpolate(0.5)
+          expect(tangent).to eql subject.tangent(0.5)
+        end
+
+        it 'returns exact length on #length' do
+          expect(subject.length).to be_within(0.000001).of 1.951859
+        end
+
+        it 'ignores number of steps on #length' do
+          expect(subject.length 10).to be_within(0.000001).of 1.951859
+        end
+
+        it 'elevates to cubic on #elevate' do
+          interpolated = subject.elevate.interpolate 0.5
+
+          expect(interpolated.x).to be_within(0.000001).of 0.75
+          expect(interpolated.y).to be_within(0.000001).of 0.5
+          expect(interpolated.z).to be_within(0.000001).of 0.75
         end
 
-        it 'returns approximated length on #length with custom number of steps' do
-          expect(subject.length 10).to be_within(0.01).of 1.950975
+        it 'flattens to Vectors on #flatten' do
+          points = subject.flatten 0.01
+
+          expect(points.first).to eql Vector.forward
+          expect(points.last).to eql Vector.left
+        end
+      end
+
+      context 'when straight' do
+        subject { Bezier.new Vector.zero, Vector.uniform(1.0), Vector.uniform(2.0) }
+
+        it 'returns direction on #tangent' do
+          expect(subject.tangent 0.5).to eql Vector.uniform(1.0).norm
+        end
+
+        it 'offsets to a BezierPath on #offset' do
+          expect(subject.offset(1.0, 4).class).to eql BezierPath
+        end
+
+        it 'flattens to its ends on #flatten' do
+          expect(subject.flatten 0.01).to eql [Vector.zero, Vector.uniform(2.0)]
+        end
+
+        it 'crosses a plane once on #intersect_plane' do
+          ratios = subject.intersect_plane Plane.new(Vector.up, -0.5), 10
+
+          expect(ratios.size).to eql 1
+          expect(ratios.first).to be_within(0.00001).of 0.25
         end
       end
     ");
@@ -573,6 +1413,18 @@ mod test_bezier_path {
           expect(interpolated.y).to be_within(0.000001).of 5.0
           expect(interpolated.z).to be_within(0.000001).of 0.0
         end
+
+        it 'finds closest point on second curve on #closest_point' do
+          ratio, point = subject.closest_point Vector.new(7.0, 5.0, 0.0), 8
+
+          expect(ratio).to be_within(0.000001).of 0.6
+          expect(point).to eql Vector.new(6.0, 6.0, 0.0)
+        end
+
+        it 'reverses direction on #reverse' do
+          expect(subject.reverse.interpolate 0.0).to eql Vector.new(10.0, 10.0, 0.0)
+          expect(subject.reverse.interpolate 1.0).to eql Vector.new(0.0, 0.0, 0.0)
+        end
       end
     ");
 }
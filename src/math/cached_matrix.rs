@@ -0,0 +1,119 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::cell::Cell;
+
+use math::Matrix;
+
+/// A `struct` wrapping a `Matrix` and memoizing its inverse, useful when the same matrix is
+/// inverted every frame while rarely changing.
+///
+/// The inverse is computed lazily, on the first call to `inv`, and is reused until the matrix is
+/// replaced with `set`.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::CachedMatrix;
+/// # use anima_engine::math::Matrix;
+/// # use anima_engine::math::Vector;
+/// let mut m = CachedMatrix::new(Matrix::ident().trans(Vector::one()));
+///
+/// assert_eq!(m.inv(), Matrix::ident().trans(-Vector::one()));
+///
+/// m.set(Matrix::ident());
+///
+/// assert_eq!(m.inv(), Matrix::ident());
+/// ```
+#[derive(Clone, Debug)]
+pub struct CachedMatrix {
+    matrix: Matrix,
+    inverse: Cell<Option<Matrix>>
+}
+
+impl CachedMatrix {
+    /// Creates a cached matrix wrapping `matrix`, with no inverse computed yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::CachedMatrix;
+    /// # use anima_engine::math::Matrix;
+    /// let m = CachedMatrix::new(Matrix::ident());
+    ///
+    /// assert_eq!(m.get(), Matrix::ident());
+    /// assert!(!m.is_inv_cached());
+    /// ```
+    pub fn new(matrix: Matrix) -> CachedMatrix {
+        CachedMatrix {
+            matrix: matrix,
+            inverse: Cell::new(None)
+        }
+    }
+
+    /// Returns the wrapped matrix.
+    pub fn get(&self) -> Matrix {
+        self.matrix
+    }
+
+    /// Replaces the wrapped matrix and invalidates its cached inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::CachedMatrix;
+    /// # use anima_engine::math::Matrix;
+    /// let mut m = CachedMatrix::new(Matrix::ident());
+    ///
+    /// m.inv();
+    /// m.set(Matrix::new([2.0; 16]));
+    ///
+    /// assert_eq!(m.get(), Matrix::new([2.0; 16]));
+    /// assert!(!m.is_inv_cached());
+    /// ```
+    pub fn set(&mut self, matrix: Matrix) {
+        self.matrix = matrix;
+        self.inverse.set(None);
+    }
+
+    /// Returns the inverse of the wrapped matrix, computing it only if it is not cached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::CachedMatrix;
+    /// # use anima_engine::math::Matrix;
+    /// let m = CachedMatrix::new(Matrix::ident());
+    ///
+    /// assert_eq!(m.inv(), Matrix::ident());
+    /// assert!(m.is_inv_cached());
+    /// ```
+    pub fn inv(&self) -> Matrix {
+        match self.inverse.get() {
+            Some(inverse) => inverse,
+            None          => {
+                let inverse = self.matrix.inv();
+
+                self.inverse.set(Some(inverse));
+
+                inverse
+            }
+        }
+    }
+
+    /// Checks whether the inverse of the wrapped matrix is currently cached.
+    pub fn is_inv_cached(&self) -> bool {
+        self.inverse.get().is_some()
+    }
+}
+
+// The cached inverse is derived from the wrapped matrix, so it does not take part in equality.
+impl PartialEq for CachedMatrix {
+    fn eq(&self, other: &CachedMatrix) -> bool {
+        self.matrix == other.matrix
+    }
+}
@@ -0,0 +1,179 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use math::{Matrix, Ray, Vector};
+
+/// A `struct` combining a view `Matrix` (world to eye space) and a projection `Matrix` (eye to
+/// clip space) in order to convert between world and screen coordinates.
+///
+/// Screen coordinates follow `Matrix::viewport`: the origin is the bottom-left corner of the
+/// viewport, *x* grows right up to `viewport_w`, *y* grows up up to `viewport_h`, and depth
+/// maps the OpenGL normalized device range `[-1.0, 1.0]` (near to far plane) to `[0.0, 1.0]`.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Camera;
+/// # use anima_engine::math::Matrix;
+/// # use anima_engine::math::Vector;
+/// let c = Camera::new(Matrix::ident(), Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0));
+///
+/// assert_eq!(c.world_to_screen(Vector::new(0.0, 0.0, -1.0), 800.0, 600.0), (400.0, 300.0, 0.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Camera {
+    /// `Matrix` transforming world coordinates to eye coordinates
+    pub view: Matrix,
+    /// `Matrix` transforming eye coordinates to clip coordinates
+    pub proj: Matrix
+}
+
+impl Camera {
+    /// Creates a camera using a view and a projection matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Camera;
+    /// # use anima_engine::math::Matrix;
+    /// let c = Camera::new(Matrix::ident(), Matrix::ident());
+    ///
+    /// assert_eq!(c, Camera { view: Matrix::ident(), proj: Matrix::ident() });
+    /// ```
+    pub fn new(view: Matrix, proj: Matrix) -> Camera {
+        Camera {
+            view: view,
+            proj: proj
+        }
+    }
+
+    /// Projects a point from world coordinates to screen coordinates, returning *x*, *y* and
+    /// depth. Points on the near plane have depth `0.0`, while points on the far plane have
+    /// depth `1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Camera;
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// let c = Camera::new(Matrix::ident(), Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0));
+    ///
+    /// assert_eq!(c.world_to_screen(Vector::new(1.0, 1.0, -1.0), 800.0, 600.0),
+    ///            (800.0, 600.0, 0.0));
+    /// assert_eq!(c.world_to_screen(Vector::new(-3.0, -3.0, -3.0), 800.0, 600.0),
+    ///            (0.0, 0.0, 1.0));
+    /// ```
+    pub fn world_to_screen(&self, point: Vector, viewport_w: f32,
+                           viewport_h: f32) -> (f32, f32, f32) {
+        let screen = self.screen_matrix(viewport_w, viewport_h) * point;
+
+        (screen.x, screen.y, screen.z)
+    }
+
+    /// Unprojects a screen position into a world space `Ray` starting on the near plane and
+    /// pointing towards the far plane, e.g. for picking. Returns `None` when the view and
+    /// projection matrices are not invertible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Camera;
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Ray;
+    /// # use anima_engine::math::Vector;
+    /// let c = Camera::new(Matrix::ident(), Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0));
+    ///
+    /// let r = c.screen_to_ray(400.0, 300.0, 800.0, 600.0);
+    ///
+    /// assert_eq!(r, Some(Ray::new(Vector::new(0.0, 0.0, -1.0), Vector::new(0.0, 0.0, -1.0))));
+    /// ```
+    pub fn screen_to_ray(&self, x: f32, y: f32, viewport_w: f32,
+                         viewport_h: f32) -> Option<Ray> {
+        self.screen_matrix(viewport_w, viewport_h).try_inv().map(|inverse| {
+            let near = inverse * Vector::new(x, y, 0.0);
+            let far = inverse * Vector::new(x, y, 1.0);
+
+            Ray::new(near, far - near)
+        })
+    }
+
+    fn screen_matrix(&self, viewport_w: f32, viewport_h: f32) -> Matrix {
+        Matrix::viewport(0.0, 0.0, viewport_w, viewport_h) * self.proj * self.view
+    }
+}
+
+use mrusty::*;
+
+mrusty_class!(Camera, {
+    def!("initialize", |mruby, view: Value, proj: Value| {
+        let view = try_obj!(mruby, view, Matrix);
+        let proj = try_obj!(mruby, proj, Matrix);
+
+        Camera::new(view, proj)
+    });
+
+    def!("view", |mruby, slf: Camera| {
+        mruby.obj(slf.view)
+    });
+
+    def!("proj", |mruby, slf: Camera| {
+        mruby.obj(slf.proj)
+    });
+
+    def!("world_to_screen", |mruby, slf: Camera, point: Value, viewport_w: f64, viewport_h: f64| {
+        let point = try_obj!(mruby, point, Vector);
+
+        let (x, y, depth) = slf.world_to_screen(point, viewport_w as f32, viewport_h as f32);
+
+        mruby.array(vec![mruby.float(x as f64), mruby.float(y as f64),
+                         mruby.float(depth as f64)])
+    });
+
+    def!("screen_to_ray", |mruby, slf: Camera, x: f64, y: f64, viewport_w: f64,
+                           viewport_h: f64| {
+        match slf.screen_to_ray(x as f32, y as f32, viewport_w as f32, viewport_h as f32) {
+            Some(ray) => mruby.obj(ray),
+            None      => mruby.nil()
+        }
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::Camera;
+    use super::super::Matrix;
+    use super::super::Ray;
+    use super::super::Vector;
+
+    describe!(Camera, (Matrix, Ray, Vector), "
+      context 'when looking towards -z' do
+        subject { Camera.new Matrix.identity, Matrix.frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0) }
+
+        it 'projects to the center of the screen on #world_to_screen' do
+          expect(subject.world_to_screen Vector.new(0.0, 0.0, -1.0), 800.0, 600.0).to eql(
+            [400.0, 300.0, 0.0]
+          )
+        end
+
+        it 'unprojects the center of the screen on #screen_to_ray' do
+          ray = subject.screen_to_ray 400.0, 300.0, 800.0, 600.0
+
+          expect(ray.origin).to eql Vector.new(0.0, 0.0, -1.0)
+          expect(ray.dir).to eql Vector.new(0.0, 0.0, -1.0)
+        end
+
+        it 'returns nil for singular matrices on #screen_to_ray' do
+          camera = Camera.new Matrix.new([0.0] * 16), subject.proj
+
+          expect(camera.screen_to_ray 400.0, 300.0, 800.0, 600.0).to be_nil
+        end
+      end
+    ");
+}
@@ -0,0 +1,298 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// A `struct` containing an RGBA color with channels between `0.0` and `1.0`.
+///
+/// Colors coming from hex codes, images or color pickers are gamma-encoded (sRGB), where equal
+/// steps in value are roughly equal steps in perceived brightness, but not in light intensity.
+/// Blending sRGB channels directly (`lerp`) therefore produces midpoints that are too dark.
+/// `Interpolate` avoids this by converting to linear space with `to_linear`, blending, and
+/// converting back with `to_srgb`. Alpha is always linear and is never converted.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Color;
+/// # use anima_engine::math::Interpolate;
+/// let black = Color::from_hex(0x000000ff);
+/// let white = Color::from_hex(0xffffffff);
+///
+/// assert_eq!(black.lerp(white, 0.5).to_hex(), 0x808080ff);
+/// assert_eq!(black.interpolate(white, 0.5).to_hex(), 0xbcbcbcff);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Color {
+    /// `f32` red channel
+    pub r: f32,
+    /// `f32` green channel
+    pub g: f32,
+    /// `f32` blue channel
+    pub b: f32,
+    /// `f32` alpha channel
+    pub a: f32
+}
+
+impl Color {
+    /// Creates a color using 4 channels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Color;
+    /// let c = Color::new(1.0, 0.5, 0.0, 1.0);
+    ///
+    /// assert_eq!(c, Color { r: 1.0, g: 0.5, b: 0.0, a: 1.0 });
+    /// ```
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Color {
+        Color { r: r, g: g, b: b, a: a }
+    }
+
+    /// Creates a color from a `0xRRGGBBAA` hex code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Color;
+    /// let c = Color::from_hex(0xff0000ff);
+    ///
+    /// assert_eq!(c, Color::new(1.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn from_hex(hex: u32) -> Color {
+        let channel = |shift: u32| ((hex >> shift) & 0xff) as f32 / 255.0;
+
+        Color {
+            r: channel(24),
+            g: channel(16),
+            b: channel(8),
+            a: channel(0)
+        }
+    }
+
+    /// Computes the `0xRRGGBBAA` hex code of a color. Channels are clamped between `0.0` and
+    /// `1.0` and rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Color;
+    /// let c = Color::new(1.0, 0.5, 2.0, 1.0);
+    ///
+    /// assert_eq!(c.to_hex(), 0xff80ffff);
+    /// ```
+    pub fn to_hex(&self) -> u32 {
+        let channel = |value: f32| (value.max(0.0).min(1.0) * 255.0).round() as u32;
+
+        channel(self.r) << 24 | channel(self.g) << 16 | channel(self.b) << 8 | channel(self.a)
+    }
+
+    /// Linearly interpolates the channels of two colors as they are, without gamma correction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Color;
+    /// let c1 = Color::new(0.0, 0.0, 0.0, 0.0);
+    /// let c2 = Color::new(1.0, 1.0, 1.0, 1.0);
+    ///
+    /// assert_eq!(c1.lerp(c2, 0.25), Color::new(0.25, 0.25, 0.25, 0.25));
+    /// ```
+    pub fn lerp(&self, other: Color, ratio: f32) -> Color {
+        let lerp = |from: f32, to: f32| (1.0 - ratio) * from + ratio * to;
+
+        Color {
+            r: lerp(self.r, other.r),
+            g: lerp(self.g, other.g),
+            b: lerp(self.b, other.b),
+            a: lerp(self.a, other.a)
+        }
+    }
+
+    /// Converts an sRGB color to linear space. Alpha is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Color;
+    /// let c = Color::new(0.0, 0.5, 1.0, 0.5).to_linear();
+    ///
+    /// assert_eq!((c.r, c.b, c.a), (0.0, 1.0, 0.5));
+    /// assert!((c.g - 0.21404114).abs() < 0.00001);
+    /// ```
+    pub fn to_linear(&self) -> Color {
+        fn convert(value: f32) -> f32 {
+            if value <= 0.04045 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        Color {
+            r: convert(self.r),
+            g: convert(self.g),
+            b: convert(self.b),
+            a: self.a
+        }
+    }
+
+    /// Converts a linear color to sRGB space. Alpha is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Color;
+    /// let c = Color::new(0.2, 0.4, 0.6, 1.0);
+    ///
+    /// assert!((c.to_linear().to_srgb().g - c.g).abs() < 0.00001);
+    /// ```
+    pub fn to_srgb(&self) -> Color {
+        fn convert(value: f32) -> f32 {
+            if value <= 0.0031308 {
+                value * 12.92
+            } else {
+                1.055 * value.powf(1.0 / 2.4) - 0.055
+            }
+        }
+
+        Color {
+            r: convert(self.r),
+            g: convert(self.g),
+            b: convert(self.b),
+            a: self.a
+        }
+    }
+}
+
+use mrusty::*;
+
+use math::Interpolate;
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: Color, ratio: f32) -> Color {
+        self.to_linear().lerp(other.to_linear(), ratio).to_srgb()
+    }
+}
+
+mrusty_class!(Color, {
+    def!("initialize", |r: f64, g: f64, b: f64, a: f64| {
+        Color::new(r as f32, g as f32, b as f32, a as f32)
+    });
+
+    // mruby Fixnums are too small for 0xRRGGBBAA, so hex codes are passed around as Strings.
+    def_self!("from_hex", |mruby, _slf: Value, hex: str| {
+        let digits = hex.trim_start_matches('#');
+
+        match u32::from_str_radix(digits, 16) {
+            Ok(value) if digits.len() == 8 => mruby.obj(Color::from_hex(value)),
+            _ => mruby.raise("ArgumentError", "hex should be formatted as RRGGBBAA")
+        }
+    });
+
+    def!("r", |mruby, slf: Color| {
+        mruby.float(slf.r as f64)
+    });
+
+    def!("g", |mruby, slf: Color| {
+        mruby.float(slf.g as f64)
+    });
+
+    def!("b", |mruby, slf: Color| {
+        mruby.float(slf.b as f64)
+    });
+
+    def!("a", |mruby, slf: Color| {
+        mruby.float(slf.a as f64)
+    });
+
+    def!("==", |mruby, slf: Color, other: Value| {
+        let other = try_obj!(other, Color, else mruby.bool(false));
+
+        let result = slf.r == other.r &&
+                     slf.g == other.g &&
+                     slf.b == other.b &&
+                     slf.a == other.a;
+
+        mruby.bool(result)
+    });
+
+    def!("to_s", |mruby, slf: Color| {
+        let string = format!("<Color: @r={} @g={} @b={} @a={}>", slf.r, slf.g, slf.b, slf.a);
+
+        mruby.string(&string)
+    });
+
+    def!("to_hex", |mruby, slf: Color| {
+        mruby.string(&format!("#{:08x}", slf.to_hex()))
+    });
+
+    def!("lerp", |mruby, slf: Color, other: Value, ratio: f64| {
+        let other = try_obj!(mruby, other, Color);
+
+        mruby.obj(slf.lerp(other, ratio as f32))
+    });
+
+    def!("interpolate", |mruby, slf: Color, other: Value, ratio: f64| {
+        let other = try_obj!(mruby, other, Color);
+
+        mruby.obj(slf.interpolate(other, ratio as f32))
+    });
+
+    def!("to_linear", |mruby, slf: Color| {
+        mruby.obj(slf.to_linear())
+    });
+
+    def!("to_srgb", |mruby, slf: Color| {
+        mruby.obj(slf.to_srgb())
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::Color;
+    use super::super::Interpolator;
+
+    describe!(Color, (Interpolator), "
+      context 'when red' do
+        subject { Color.new 1.0, 0.0, 0.0, 1.0 }
+
+        it { is_expected.to eql Color.from_hex('#ff0000ff') }
+
+        it 'returns channels on #r, #g, #b, #a' do
+          expect([subject.r, subject.g, subject.b, subject.a]).to eql [1.0, 0.0, 0.0, 1.0]
+        end
+
+        it 'converts to hex String on #to_hex' do
+          expect(subject.to_hex).to eql '#ff0000ff'
+        end
+
+        it 'converts to String on #to_s' do
+          expect(subject.to_s).to eql '<Color: @r=1 @g=0 @b=0 @a=1>'
+        end
+      end
+
+      context 'when blending black and white' do
+        let(:black) { Color.from_hex '000000ff' }
+        let(:white) { Color.from_hex 'ffffffff' }
+
+        it 'blends sRGB channels on #lerp' do
+          expect(black.lerp(white, 0.5).to_hex).to eql '#808080ff'
+        end
+
+        it 'blends linear channels on #interpolate' do
+          expect(black.interpolate(white, 0.5).to_hex).to eql '#bcbcbcff'
+        end
+
+        it 'tweens with Interpolator#ratio on #interpolate' do
+          interpolator = Interpolator.new 1.0, 2.0, :linear
+
+          expect(black.interpolate(white, interpolator.ratio(2.0)).to_hex).to eql '#bcbcbcff'
+        end
+      end
+    ");
+}
@@ -0,0 +1,44 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use math::Vector;
+
+/// A `trait` implemented by parametric curves, like `Bezier` and `BezierPath`, useful for writing
+/// path-following code that works with any curve.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Bezier;
+/// # use anima_engine::math::BezierPath;
+/// # use anima_engine::math::Curve;
+/// # use anima_engine::math::Vector;
+/// fn midpoint(curve: &dyn Curve) -> Vector {
+///     curve.interpolate(0.5)
+/// }
+///
+/// let b = Bezier::new_sqr(
+///     Vector::new(0.0, 0.0, 0.0),
+///     Vector::new(1.0, 0.0, 0.0),
+///     Vector::new(2.0, 0.0, 0.0)
+/// );
+/// let p = BezierPath::new(vec![b]);
+///
+/// assert_eq!(midpoint(&b), Vector::new(1.0, 0.0, 0.0));
+/// assert_eq!(midpoint(&p), Vector::new(1.0, 0.0, 0.0));
+/// ```
+pub trait Curve {
+    /// Computes the point on the curve correspoding to a `ratio` (between `0.0` and `1.0`).
+    fn interpolate(&self, ratio: f32) -> Vector;
+
+    /// Computes the approximated length of the curve using `steps` samples.
+    fn len(&self, steps: i32) -> f32;
+
+    /// Computes the normalized tangent of the curve correspoding to a `ratio` (between `0.0` and
+    /// `1.0`).
+    fn tangent(&self, ratio: f32) -> Vector;
+}
@@ -0,0 +1,175 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use math::{Aabb, Matrix, Plane, Vector};
+
+/// A view frustum `struct` made of six clip planes, useful for culling.
+///
+/// Every `Plane`'s unit normal points inside the frustum, so that a point `p` is on the inner
+/// side of the plane when `plane.signed_dist(p) >= 0.0`. Planes are stored in the order: left,
+/// right, bottom, top, near, far.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Frustum;
+/// # use anima_engine::math::Matrix;
+/// # use anima_engine::math::Vector;
+/// let f = Frustum::from_matrix(Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0));
+///
+/// assert!(f.contains_point(Vector::new(0.0, 0.0, -2.0)));
+/// assert!(!f.contains_point(Vector::new(0.0, 0.0, 2.0)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Frustum {
+    /// `[Plane; 6]` inward-facing planes
+    pub planes: [Plane; 6]
+}
+
+impl Frustum {
+    /// Extracts the clip planes of a projection (or projection × view) matrix, with the
+    /// Gribb-Hartmann method. The matrix is expected to map to the OpenGL clip range, like
+    /// `Matrix::frustum` does, and planes are in the space the matrix transforms from, e.g.
+    /// world space for a projection × view matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Frustum;
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Plane;
+    /// # use anima_engine::math::Vector;
+    /// let f = Frustum::from_matrix(Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0));
+    ///
+    /// assert_eq!(f.planes[4], Plane::new(Vector::new(0.0, 0.0, -1.0), -1.0));
+    /// ```
+    pub fn from_matrix(view_proj: Matrix) -> Frustum {
+        let m = view_proj.array;
+        let row = |i: usize| [m[i], m[4 + i], m[8 + i], m[12 + i]];
+
+        let (r0, r1, r2, r3) = (row(0), row(1), row(2), row(3));
+
+        let plane = |sign: f32, r: [f32; 4]| {
+            let normal = Vector::new(r3[0] + sign * r[0], r3[1] + sign * r[1],
+                                     r3[2] + sign * r[2]);
+
+            Plane::new(normal, r3[3] + sign * r[3])
+        };
+
+        Frustum {
+            planes: [
+                plane(1.0, r0),
+                plane(-1.0, r0),
+                plane(1.0, r1),
+                plane(-1.0, r1),
+                plane(1.0, r2),
+                plane(-1.0, r2)
+            ]
+        }
+    }
+
+    /// Checks whether a point lies inside the frustum. Points on the planes are inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Frustum;
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// let f = Frustum::from_matrix(Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0));
+    ///
+    /// assert!(f.contains_point(Vector::new(1.0, 1.0, -1.0)));
+    /// assert!(!f.contains_point(Vector::new(0.0, 0.0, -0.5)));
+    /// assert!(!f.contains_point(Vector::new(0.0, 0.0, -4.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector) -> bool {
+        const EPSILON: f32 = 0.00001;
+
+        self.planes.iter().all(|plane| plane.signed_dist(point) >= -EPSILON)
+    }
+
+    /// Checks whether a box intersects or lies inside the frustum. The test is conservative:
+    /// boxes near the frustum's edges may be reported as intersecting while being outside, but
+    /// boxes that intersect are never reported as outside, which is what culling needs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Aabb;
+    /// # use anima_engine::math::Frustum;
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// let f = Frustum::from_matrix(Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0));
+    ///
+    /// assert!(f.intersects_aabb(&Aabb::new(Vector::new(-5.0, -5.0, -2.0), Vector::new_unf(5.0))));
+    /// assert!(!f.intersects_aabb(&Aabb::new(Vector::zero(), Vector::new(1.0, 1.0, 0.5))));
+    /// ```
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.planes.iter().all(|plane| {
+            let normal = plane.normal;
+
+            // The corner farthest along the normal is the last one to leave the inner side.
+            let corner = Vector::new(
+                if normal.x >= 0.0 { aabb.max.x } else { aabb.min.x },
+                if normal.y >= 0.0 { aabb.max.y } else { aabb.min.y },
+                if normal.z >= 0.0 { aabb.max.z } else { aabb.min.z }
+            );
+
+            plane.signed_dist(corner) >= 0.0
+        })
+    }
+}
+
+use mrusty::*;
+
+mrusty_class!(Frustum, {
+    def!("initialize", |mruby, view_proj: Value| {
+        let view_proj = try_obj!(mruby, view_proj, Matrix);
+
+        Frustum::from_matrix(view_proj)
+    });
+
+    def!("contains_point?", |mruby, slf: Frustum, point: Value| {
+        let point = try_obj!(mruby, point, Vector);
+
+        mruby.bool(slf.contains_point(point))
+    });
+
+    def!("intersects_aabb?", |mruby, slf: Frustum, aabb: Value| {
+        let aabb = try_obj!(mruby, aabb, Aabb);
+
+        mruby.bool(slf.intersects_aabb(&aabb))
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::Frustum;
+    use super::super::Aabb;
+    use super::super::Matrix;
+    use super::super::Vector;
+
+    describe!(Frustum, (Aabb, Matrix, Vector), "
+      context 'when perspective' do
+        subject { Frustum.new Matrix.frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0) }
+
+        it 'contains points between the planes on #contains_point?' do
+          expect(subject.contains_point? Vector.new(0.0, 0.0, -2.0)).to be true
+          expect(subject.contains_point? Vector.new(0.0, 0.0, -0.5)).to be false
+        end
+
+        it 'intersects overlapping boxes on #intersects_aabb?' do
+          expect(subject.intersects_aabb? Aabb.new(Vector.uniform(-5.0), Vector.uniform(5.0))).to(
+            be true
+          )
+          expect(subject.intersects_aabb? Aabb.new(Vector.zero, Vector.uniform(0.5))).to be false
+        end
+      end
+    ");
+}
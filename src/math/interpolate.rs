@@ -24,5 +24,26 @@
 /// }
 /// ```
 pub trait Interpolate {
+    /// Interpolates between `self` (`ratio = 0.0`) and `other` (`ratio = 1.0`). Ratios outside
+    /// of `[0.0, 1.0]` are not clamped and usually extrapolate past the ends; use
+    /// `interpolate_clamped` when that is not wanted.
     fn interpolate(&self, other: Self, ratio: f32) -> Self;
+
+    /// Interpolates like `interpolate` after clamping `ratio` between `0.0` and `1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Interpolate;
+    /// # use anima_engine::math::Vector;
+    /// let v1 = Vector::zero();
+    /// let v2 = Vector::one();
+    ///
+    /// assert_eq!(v1.interpolate(v2, 1.5), Vector::new_unf(1.5));
+    /// assert_eq!(v1.interpolate_clamped(v2, 1.5), v2);
+    /// assert_eq!(v1.interpolate_clamped(v2, -0.5), v1);
+    /// ```
+    fn interpolate_clamped(&self, other: Self, ratio: f32) -> Self where Self: Sized {
+        self.interpolate(other, ratio.max(0.0).min(1.0))
+    }
 }
@@ -6,6 +6,9 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use std::f32::consts;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
 
 /// A `struct` useful to easily compute interpolation ratios.
 ///
@@ -59,6 +62,20 @@ pub struct Interpolator {
 }
 
 /// An `enum` containing useful interpolation techniques.
+///
+/// Behaviors can be parsed from and displayed as their lowercase names: `"linear"`, `"acc"`,
+/// `"dec"` and `"accdec"`.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Behavior;
+/// let b: Behavior = "accdec".parse().unwrap();
+///
+/// assert_eq!(b, Behavior::AccDec);
+/// assert_eq!(b.to_string(), "accdec");
+/// assert!("bounce".parse::<Behavior>().is_err());
+/// ```
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Behavior {
     /// linear, *i(t) = t*
@@ -71,6 +88,63 @@ pub enum Behavior {
     AccDec
 }
 
+impl Behavior {
+    /// Returns all behaviors, e.g. to list them in scripts or documentation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Behavior;
+    /// let names: Vec<String> = Behavior::all().iter().map(|b| b.to_string()).collect();
+    ///
+    /// assert_eq!(names, vec!["linear", "acc", "dec", "accdec"]);
+    /// ```
+    pub fn all() -> &'static [Behavior] {
+        // Has no wildcard arm, so adding a variant fails to compile until it is listed in ALL too.
+        fn _assert_exhaustive(behavior: Behavior) {
+            match behavior {
+                Behavior::Linear | Behavior::Acc | Behavior::Dec | Behavior::AccDec => ()
+            }
+        }
+
+        const ALL: &'static [Behavior] = &[
+            Behavior::Linear,
+            Behavior::Acc,
+            Behavior::Dec,
+            Behavior::AccDec
+        ];
+
+        ALL
+    }
+}
+
+impl FromStr for Behavior {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Behavior, String> {
+        match s {
+            "linear" => Ok(Behavior::Linear),
+            "acc"    => Ok(Behavior::Acc),
+            "dec"    => Ok(Behavior::Dec),
+            "accdec" => Ok(Behavior::AccDec),
+            _        => Err(format!("unknown behavior `{}`", s))
+        }
+    }
+}
+
+impl fmt::Display for Behavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Behavior::Linear => "linear",
+            Behavior::Acc    => "acc",
+            Behavior::Dec    => "dec",
+            Behavior::AccDec => "accdec"
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
 impl Interpolator {
     /// Creates an interpolator by defining its starting time, duration and behavior.
     ///
@@ -91,7 +165,51 @@ impl Interpolator {
         }
     }
 
-    /// Computes the ratio (between `0.0` and `1.0`) for some given time.
+    /// Creates an interpolator by defining its starting time and duration as `Duration`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Interpolator;
+    /// # use anima_engine::math::Behavior;
+    /// # use std::time::Duration;
+    /// let i = Interpolator::new_dur(Duration::from_secs(1), Duration::from_millis(500),
+    ///                               Behavior::Linear);
+    ///
+    /// assert_eq!(i, Interpolator { start: 1.0, duration: 0.5, behavior: Behavior::Linear });
+    /// ```
+    pub fn new_dur(start: Duration, duration: Duration, behavior: Behavior) -> Interpolator {
+        Interpolator {
+            start: start.as_secs_f32(),
+            duration: duration.as_secs_f32(),
+            behavior: behavior
+        }
+    }
+
+    /// Creates a copy of an interpolator starting `delay` later, e.g. to wait before animating
+    /// when sequencing animations. The ratio stays `0.0` during the delay.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Interpolator;
+    /// # use anima_engine::math::Behavior;
+    /// let i = Interpolator::new(1.0, 2.0, Behavior::Linear).with_delay(0.5);
+    ///
+    /// assert_eq!(i, Interpolator::new(1.5, 2.0, Behavior::Linear));
+    /// assert_eq!(i.ratio(1.25), 0.0);
+    /// assert_eq!(i.ratio(2.5), 0.5);
+    /// ```
+    pub fn with_delay(&self, delay: f32) -> Interpolator {
+        Interpolator {
+            start: self.start + delay,
+            duration: self.duration,
+            behavior: self.behavior
+        }
+    }
+
+    /// Computes the ratio (between `0.0` and `1.0`) for some given time. Times before `start`
+    /// map to `0.0` and times after `start + duration` map to `1.0`.
     ///
     /// # Examples
     ///
@@ -107,36 +225,78 @@ impl Interpolator {
     /// assert_eq!(acc.ratio(0.25), 0.0625);
     /// assert_eq!(dec.ratio(0.25), 0.4375);
     /// assert_eq!(acd.ratio(0.25), 0.14644668);
+    ///
+    /// assert_eq!(acc.ratio(-1.0), 0.0);
+    /// assert_eq!(dec.ratio(2.0), 1.0);
     /// ```
     pub fn ratio(&self, time: f32) -> f32 {
-        let ratio = self.convert(time);
+        ease(self.behavior, self.convert(time))
+    }
 
-        match self.behavior {
-            Behavior::Linear => ratio,
-            Behavior::Acc    => ratio.powi(2),
-            Behavior::Dec    => 1.0 - (1.0 - ratio).powi(2),
-            Behavior::AccDec => ((ratio + 1.0) * consts::PI).cos() / 2.0 + 0.5
-        }
+    /// Computes the ratio (between `0.0` and `1.0`) for some given elapsed `Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Interpolator;
+    /// # use anima_engine::math::Behavior;
+    /// # use std::time::Duration;
+    /// let i = Interpolator::new(0.0, 2.0, Behavior::Linear);
+    ///
+    /// assert_eq!(i.ratio_dur(Duration::from_millis(500)), 0.25);
+    /// ```
+    pub fn ratio_dur(&self, elapsed: Duration) -> f32 {
+        self.ratio(elapsed.as_secs_f32())
     }
 
     fn convert(&self, time: f32) -> f32 {
-        (time - self.start) / self.duration
+        ((time - self.start) / self.duration).max(0.0).min(1.0)
+    }
+}
+
+/// Applies the shaping function of a `Behavior` directly to some ratio `t` (between `0.0` and
+/// `1.0`), without any time window.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math;
+/// # use anima_engine::math::Behavior;
+/// assert_eq!(math::ease(Behavior::Linear, 0.25), 0.25);
+/// assert_eq!(math::ease(Behavior::Acc, 0.25), 0.0625);
+/// assert_eq!(math::ease(Behavior::Dec, 0.25), 0.4375);
+/// assert_eq!(math::ease(Behavior::AccDec, 0.25), 0.14644668);
+/// ```
+pub fn ease(behavior: Behavior, t: f32) -> f32 {
+    match behavior {
+        Behavior::Linear => t,
+        Behavior::Acc    => t.powi(2),
+        Behavior::Dec    => 1.0 - (1.0 - t).powi(2),
+        Behavior::AccDec => ((t + 1.0) * consts::PI).cos() / 2.0 + 0.5
     }
 }
 
 use mrusty::*;
 
+// Converts an mruby `Symbol` to a `Behavior`, raising an `ArgumentError` listing all behaviors
+// otherwise.
+pub fn to_behavior(mruby: MrubyType, value: &Value) -> Result<Behavior, Value> {
+    match value.to_str().ok().and_then(|behavior| behavior.parse().ok()) {
+        Some(behavior) => Ok(behavior),
+        None           => {
+            let names: Vec<String> = Behavior::all().iter().map(|b| format!(":{}", b)).collect();
+            let message = format!("behavior must be one of {}", names.join(", "));
+
+            Err(mruby.raise("ArgumentError", &message))
+        }
+    }
+}
+
 mrusty_class!(Interpolator, {
     def!("initialize", |mruby, start: f64, duration: f64, behavior: Value| {
-        let behavior = match behavior.to_str().unwrap() {
-            "linear" => Behavior::Linear,
-            "acc"    => Behavior::Acc,
-            "dec"    => Behavior::Dec,
-            "accdec" => Behavior::AccDec,
-            _        => {
-                return mruby.raise("ArgumentError",
-                                   "behavior must be one of :linear, :acc, :dec, :accdec")
-            }
+        let behavior = match to_behavior(mruby.clone(), &behavior) {
+            Ok(behavior) => behavior,
+            Err(error)   => return error
         };
 
         Interpolator::new(start as f32, duration as f32, behavior)
@@ -151,17 +311,12 @@ mrusty_class!(Interpolator, {
     });
 
     def!("behavior", |mruby, slf: Interpolator| {
-        let behavior = match slf.behavior {
-            Behavior::Linear => "linear",
-            Behavior::Acc    => "acc",
-            Behavior::Dec    => "dec",
-            Behavior::AccDec => "accdec"
-        };
-
-        mruby.symbol(behavior)
+        mruby.symbol(&slf.behavior.to_string())
     });
 
-    def!("==", |mruby, slf: Interpolator, other: Interpolator| {
+    def!("==", |mruby, slf: Interpolator, other: Value| {
+        let other = try_obj!(other, Interpolator, else mruby.bool(false));
+
         let result = slf.start == other.start &&
                      slf.duration == other.duration &&
                      slf.behavior == other.behavior;
@@ -170,19 +325,16 @@ mrusty_class!(Interpolator, {
     });
 
     def!("to_s", |mruby, slf: Interpolator| {
-        let behavior = match slf.behavior {
-            Behavior::Linear => "linear",
-            Behavior::Acc    => "acc",
-            Behavior::Dec    => "dec",
-            Behavior::AccDec => "accdec"
-        };
-
         let string = format!("<Interpolator: @start={} @duration={} @behavior=:{}>",
-                             slf.start, slf.duration, behavior);
+                             slf.start, slf.duration, slf.behavior);
 
         mruby.string(&string)
     });
 
+    def!("with_delay", |mruby, slf: Interpolator, delay: f64| {
+        mruby.obj(slf.with_delay(delay as f32))
+    });
+
     def!("ratio", |mruby, slf: Interpolator, ratio: f64| {
         mruby.float(slf.ratio(ratio as f32) as f64)
     });
@@ -204,6 +356,15 @@ mod tests {
           expect(subject.ratio 0.25).to eql 0.25
         end
 
+        it 'clamps outside the window on #ratio' do
+          expect(subject.ratio -1.0).to eql 0.0
+          expect(subject.ratio 2.0).to eql 1.0
+        end
+
+        it 'starts later on #with_delay' do
+          expect(subject.with_delay 0.5).to eql Interpolator.new(0.5, 1.0, :linear)
+        end
+
         it 'returns start on #start' do
           expect(subject.start).to eql 0.0
         end
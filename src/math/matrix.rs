@@ -45,6 +45,30 @@ impl Matrix {
         Matrix { array: array }
     }
 
+    /// Creates a matrix from a slice of values (columns incremented first), e.g. read from a file
+    /// or through FFI. Fails if the slice does not contain exactly 16 values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// let values = vec![1.0; 16];
+    ///
+    /// assert_eq!(Matrix::try_from_slice(&values), Ok(Matrix::new([1.0; 16])));
+    /// assert!(Matrix::try_from_slice(&values[..15]).is_err());
+    /// ```
+    pub fn try_from_slice(data: &[f32]) -> Result<Matrix, &'static str> {
+        if data.len() != 16 {
+            return Err("slice should contain 16 values");
+        }
+
+        let mut array = [0.0; 16];
+
+        array.copy_from_slice(data);
+
+        Ok(Matrix::new(array))
+    }
+
     /// Creates an identity (1.0 on primary diagonal) matrix.
     ///
     /// # Examples
@@ -66,6 +90,198 @@ impl Matrix {
         Matrix { array: array }
     }
 
+    /// Creates an off-center perspective projection matrix. (like `glFrustum`)
+    ///
+    /// The camera looks towards `-z`; `left`, `right`, `bottom` and `top` bound the near plane,
+    /// while `near` and `far` are positive distances. Depth is mapped to the OpenGL clip range:
+    /// `-near` maps to `-1.0` and `-far` to `1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// let m = Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0);
+    ///
+    /// assert_eq!(m * Vector::new(1.0, 1.0, -1.0), Vector::new(1.0, 1.0, -1.0));
+    /// assert_eq!(m * Vector::new(0.0, 0.0, -3.0), Vector::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn frustum(left: f32, right: f32, bottom: f32, top: f32, near: f32,
+                   far: f32) -> Matrix {
+        let mut array = [0.0; 16];
+
+        array[0]  = 2.0 * near / (right - left);
+        array[5]  = 2.0 * near / (top - bottom);
+        array[8]  = (right + left) / (right - left);
+        array[9]  = (top + bottom) / (top - bottom);
+        array[10] = -(far + near) / (far - near);
+        array[11] = -1.0;
+        array[14] = -2.0 * far * near / (far - near);
+
+        Matrix { array: array }
+    }
+
+    /// Creates a viewport matrix mapping clip coordinates to window coordinates. *x* and *y*
+    /// are mapped from `[-1.0, 1.0]` to `[x, x + width]` and `[y, y + height]`, while depth is
+    /// mapped from the OpenGL clip range `[-1.0, 1.0]` to `[0.0, 1.0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// let m = Matrix::viewport(0.0, 0.0, 800.0, 600.0);
+    ///
+    /// assert_eq!(m * Vector::new(-1.0, -1.0, -1.0), Vector::new(0.0, 0.0, 0.0));
+    /// assert_eq!(m * Vector::new(1.0, 1.0, 1.0), Vector::new(800.0, 600.0, 1.0));
+    /// ```
+    pub fn viewport(x: f32, y: f32, width: f32, height: f32) -> Matrix {
+        let mut array = [0.0; 16];
+
+        array[0]  = width / 2.0;
+        array[5]  = height / 2.0;
+        array[10] = 0.5;
+        array[12] = x + width / 2.0;
+        array[13] = y + height / 2.0;
+        array[14] = 0.5;
+        array[15] = 1.0;
+
+        Matrix { array: array }
+    }
+
+    /// Creates a shear matrix. Every factor offsets the first axis proportionally to the second
+    /// one, e.g. `xy` gives *x' = x + xy * y*. With rows *r* and columns *c* stored at
+    /// `array[c * 4 + r]`, the factors map to:
+    ///
+    /// * `xy` - row 0, column 1 (`array[4]`)
+    /// * `xz` - row 0, column 2 (`array[8]`)
+    /// * `yx` - row 1, column 0 (`array[1]`)
+    /// * `yz` - row 1, column 2 (`array[9]`)
+    /// * `zx` - row 2, column 0 (`array[2]`)
+    /// * `zy` - row 2, column 1 (`array[6]`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// let m = Matrix::shear(0.5, 0.0, 0.0, 0.0, 0.0, 2.0);
+    ///
+    /// assert_eq!(m * Vector::new(1.0, 1.0, 1.0), Vector::new(1.5, 1.0, 3.0));
+    /// ```
+    pub fn shear(xy: f32, xz: f32, yx: f32, yz: f32, zx: f32, zy: f32) -> Matrix {
+        let mut array = Matrix::ident().array;
+
+        array[4] = xy;
+        array[8] = xz;
+        array[1] = yx;
+        array[9] = yz;
+        array[2] = zx;
+        array[6] = zy;
+
+        Matrix { array: array }
+    }
+
+    /// Creates a rotation matrix from Euler angles in radians, equivalent to rotating an identity
+    /// matrix by `Quaternion::from_euler(pitch, yaw, roll)`. (roll first, then pitch, then yaw)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// # use std::f32::consts;
+    /// let m = Matrix::rotation(consts::PI / 2.0, 0.0, 0.0);
+    ///
+    /// assert!((m * Vector::up()).dist(Vector::forward()) < 0.00001);
+    /// ```
+    pub fn rotation(pitch: f32, yaw: f32, roll: f32) -> Matrix {
+        let (sx, cx) = pitch.sin_cos();
+        let (sy, cy) = yaw.sin_cos();
+        let (sz, cz) = roll.sin_cos();
+
+        Matrix {
+            array: [
+                cy * cz + sy * sx * sz,
+                cx * sz,
+                cy * sx * sz - sy * cz,
+                0.0,
+                sy * sx * cz - cy * sz,
+                cx * cz,
+                sy * sz + cy * sx * cz,
+                0.0,
+                sy * cx,
+                -sx,
+                cy * cx,
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                1.0
+            ]
+        }
+    }
+
+    /// Creates a matrix that scales, rotates and then translates. (`t * r * s`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// let t = Vector::new(1.0, 2.0, 3.0);
+    /// let r = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    /// let s = Vector::new_unf(2.0);
+    ///
+    /// assert_eq!(Matrix::from_trs(t, r, s), Matrix::ident().scale(s).rot(r).trans(t));
+    /// ```
+    pub fn from_trs(trans: Vector, rot: Quaternion, scale: Vector) -> Matrix {
+        Matrix::ident().scale(scale).rot(rot).trans(trans)
+    }
+
+    /// Creates a camera-facing (billboard) transform placed at `position`, e.g. for particles
+    /// and sprites. The local forward axis (`Vector::forward()`) points towards `camera_pos`,
+    /// while the local up axis (`Vector::up()`) stays as close to `up` as possible.
+    ///
+    /// When the view direction is parallel to `up`, an arbitrary up axis perpendicular to it is
+    /// chosen instead. When `camera_pos` coincides with `position`, the view direction falls back
+    /// to `Vector::forward()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// let p = Vector::new(1.0, 0.0, 0.0);
+    /// let m = Matrix::billboard(p, Vector::new(1.0, 0.0, 5.0), Vector::up());
+    ///
+    /// assert_eq!(m, Matrix::ident().trans(p));
+    /// ```
+    pub fn billboard(position: Vector, camera_pos: Vector, up: Vector) -> Matrix {
+        let forward = (camera_pos - position).normalize_or(Vector::forward());
+        let left = up.cross(forward);
+
+        let (left, up) = if left.len() < 0.00001 {
+            let (_, left, up) = forward.orthonormal_basis();
+
+            (left, up)
+        } else {
+            let left = left.norm();
+
+            (left, forward.cross(left))
+        };
+
+        Matrix {
+            array: [
+                left.x,     left.y,     left.z,     0.0,
+                up.x,       up.y,       up.z,       0.0,
+                forward.x,  forward.y,  forward.z,  0.0,
+                position.x, position.y, position.z, 1.0
+            ]
+        }
+    }
+
     /// Translates a matrix according to the scale represented by a vector.
     /// The translation is applied to the left. (`t * m`)
     ///
@@ -204,6 +420,170 @@ impl Matrix {
         self.trans(-point).rot(quaternion).trans(point)
     }
 
+    /// Checks whether all values of a matrix are finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// assert!(Matrix::ident().is_finite());
+    /// assert!(!Matrix::new([1.0 / 0.0; 16]).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.array.iter().all(|value| value.is_finite())
+    }
+
+    /// Checks whether any value of a matrix is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// assert!(!Matrix::ident().is_nan());
+    /// assert!(Matrix::new([0.0 / 0.0; 16]).is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.array.iter().any(|value| value.is_nan())
+    }
+
+    /// Checks whether a matrix is the identity matrix within `epsilon`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// assert!(Matrix::ident().is_identity(0.00001));
+    /// assert!(!Matrix::ident().trans(Vector::one()).is_identity(0.00001));
+    /// ```
+    pub fn is_identity(&self, epsilon: f32) -> bool {
+        self.approx_eq(Matrix::ident(), epsilon)
+    }
+
+    /// Checks whether all values of two matrices are equal within `epsilon`, which is useful when
+    /// comparing the results of computations prone to floating-point rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// let m1 = Matrix::new([1.0; 16]);
+    /// let m2 = Matrix::new([1.000001; 16]);
+    ///
+    /// assert!(m1 != m2);
+    /// assert!(m1.approx_eq(m2, 0.00001));
+    /// ```
+    pub fn approx_eq(&self, other: Matrix, epsilon: f32) -> bool {
+        self.array.iter().zip(other.array.iter()).all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+
+    /// Checks whether the upper-left 3x3 part of a matrix has orthonormal rows within `epsilon`,
+    /// i.e. whether it represents a pure rotation. (ignoring translation)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// let q = Quaternion::new_rot(Vector::up(), 1.0);
+    ///
+    /// assert!(Matrix::ident().rot(q).is_orthogonal(0.00001));
+    /// assert!(!Matrix::ident().scale(Vector::new_unf(2.0)).is_orthogonal(0.00001));
+    /// ```
+    pub fn is_orthogonal(&self, epsilon: f32) -> bool {
+        let m = self.array;
+
+        let rows = [
+            Vector::new(m[0], m[4], m[8]),
+            Vector::new(m[1], m[5], m[9]),
+            Vector::new(m[2], m[6], m[10])
+        ];
+
+        (0..3).all(|i| {
+            (0..3).all(|j| {
+                let expected = if i == j { 1.0 } else { 0.0 };
+
+                (rows[i].dot(rows[j]) - expected).abs() <= epsilon
+            })
+        })
+    }
+
+    /// Restores the orthonormality of the upper-left 3x3 part of a matrix with Gram-Schmidt,
+    /// leaving the rest, including translation, untouched. Useful against the drift accumulated
+    /// by many multiplications.
+    ///
+    /// The matrix is assumed to be *close* to a rotation: the first axis (column) keeps its
+    /// direction, the second one is made orthogonal to it and the third one is recomputed from
+    /// the first two, so a matrix far from a rotation (e.g. scaled or skewed) will change a lot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// let mut m = Matrix::ident();
+    ///
+    /// m.array[1] = 0.01;
+    ///
+    /// assert!(!m.is_orthogonal(0.00001));
+    /// assert!(m.orthonormalize().is_orthogonal(0.00001));
+    /// ```
+    pub fn orthonormalize(&self) -> Matrix {
+        let m = self.array;
+
+        let x = Vector::new(m[0], m[1], m[2]).norm();
+        let y = Vector::new(m[4], m[5], m[6]);
+        let y = (y - x * y.dot(x)).norm();
+        let z = x.cross(y);
+
+        Matrix {
+            array: [
+                x.x,   x.y,   x.z,   m[3],
+                y.x,   y.y,   y.z,   m[7],
+                z.x,   z.y,   z.z,   m[11],
+                m[12], m[13], m[14], m[15]
+            ]
+        }
+    }
+
+    /// Computes the trace of a matrix, i.e. the sum of its diagonal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// assert_eq!(Matrix::ident().trace(), 4.0);
+    /// assert_eq!(Matrix::ident().scale(Vector::new(2.0, 3.0, 4.0)).trace(), 10.0);
+    /// ```
+    pub fn trace(&self) -> f32 {
+        let m = self.array;
+
+        m[0] + m[5] + m[10] + m[15]
+    }
+
+    /// Computes the adjugate (classical adjoint) of a matrix, i.e. the transpose of its cofactor
+    /// matrix. For invertible matrices, this is the inverse scaled by the determinant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// let m = Matrix::ident().scale(Vector::new_unf(2.0));
+    ///
+    /// // diag(2, 2, 2, 1) has determinant 8 and inverse diag(0.5, 0.5, 0.5, 1).
+    /// assert_eq!(m.adjugate().array, [
+    ///     4.0, 0.0, 0.0, 0.0,
+    ///     0.0, 4.0, 0.0, 0.0,
+    ///     0.0, 0.0, 4.0, 0.0,
+    ///     0.0, 0.0, 0.0, 8.0
+    /// ]);
+    /// ```
+    pub fn adjugate(&self) -> Matrix {
+        self.adjugate_det().0
+    }
+
     /// Inverts a matrix.
     ///
     /// # Examples
@@ -213,6 +593,90 @@ impl Matrix {
     /// assert_eq!(Matrix::ident().inv(), Matrix::ident());
     /// ```
     pub fn inv(&self) -> Matrix {
+        match self.try_inv() {
+            Some(inverse) => inverse,
+            None          => panic!("Matrix {:?} is not invertable.", self.array)
+        }
+    }
+
+    /// Inverts a matrix, returning `None` when it is not invertible. (zero determinant)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// assert_eq!(Matrix::ident().try_inv(), Some(Matrix::ident()));
+    /// assert_eq!(Matrix::new([0.0; 16]).try_inv(), None);
+    /// ```
+    pub fn try_inv(&self) -> Option<Matrix> {
+        let (adjugate, det) = self.adjugate_det();
+
+        if det == 0.0 {
+            return None;
+        }
+
+        let inv_det = det.recip();
+        let mut array = adjugate.array;
+
+        for value in array.iter_mut() {
+            *value *= inv_det;
+        }
+
+        Some(Matrix::new(array))
+    }
+
+    /// Projects a point with a matrix (e.g. projection × view) to window coordinates, like
+    /// `gluProject`. The matrix is applied with a perspective divide, then normalized device
+    /// coordinates are mapped to the window like `Matrix::viewport` does.
+    ///
+    /// `viewport` is `(x, y, width, height)`, with *x* and *y* being the window coordinates of
+    /// the viewport's bottom-left corner. The resulting depth is between `0.0` (near plane) and
+    /// `1.0` (far plane).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// let m = Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0);
+    /// let viewport = (0.0, 0.0, 800.0, 600.0);
+    ///
+    /// let near = m.project(Vector::new(0.0, 0.0, -1.0), viewport);
+    /// let far = m.project(Vector::new(3.0, 3.0, -3.0), viewport);
+    ///
+    /// assert_eq!(near, Vector::new(400.0, 300.0, 0.0));
+    /// assert_eq!(far, Vector::new(800.0, 600.0, 1.0));
+    /// ```
+    pub fn project(&self, point: Vector, viewport: (f32, f32, f32, f32)) -> Vector {
+        let (x, y, width, height) = viewport;
+
+        Matrix::viewport(x, y, width, height) * *self * point
+    }
+
+    /// Unprojects a point from window coordinates, like `gluUnProject`, i.e. the inverse of
+    /// `project` with the same `viewport` layout and depth range. Returns `None` when the
+    /// matrix is not invertible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Vector;
+    /// let m = Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0);
+    /// let viewport = (0.0, 0.0, 800.0, 600.0);
+    ///
+    /// let p = m.unproject(Vector::new(400.0, 300.0, 0.0), viewport);
+    ///
+    /// assert_eq!(p, Some(Vector::new(0.0, 0.0, -1.0)));
+    /// assert_eq!(Matrix::new([0.0; 16]).unproject(Vector::zero(), viewport), None);
+    /// ```
+    pub fn unproject(&self, point: Vector, viewport: (f32, f32, f32, f32)) -> Option<Vector> {
+        let (x, y, width, height) = viewport;
+
+        (Matrix::viewport(x, y, width, height) * *self).try_inv().map(|inverse| inverse * point)
+    }
+
+    fn adjugate_det(&self) -> (Matrix, f32) {
         let m = self.array;
 
         let s0 = m[0] * m[5]  - m[1] * m[4];
@@ -231,30 +695,28 @@ impl Matrix {
 
         let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
 
-        if det == 0.0 { panic!("Matrix {:?} is not invertable.", m); }
-
-        let inv_det = det.recip();
-
-        Matrix {
+        let adjugate = Matrix {
             array: [
-                ( m[5] * c5 - m[9]  * c4 + m[13] * c3) * inv_det,
-                (-m[1] * c5 + m[9]  * c2 - m[13] * c1) * inv_det,
-                ( m[1] * c4 - m[5]  * c2 + m[13] * c0) * inv_det,
-                (-m[1] * c3 + m[5]  * c1 - m[9]  * c0) * inv_det,
-                (-m[4] * c5 + m[8]  * c4 - m[12] * c3) * inv_det,
-                ( m[0] * c5 - m[8]  * c2 + m[12] * c1) * inv_det,
-                (-m[0] * c4 + m[4]  * c2 - m[12] * c0) * inv_det,
-                ( m[0] * c3 - m[4]  * c1 + m[8]  * c0) * inv_det,
-                ( m[7] * s5 - m[11] * s4 + m[15] * s3) * inv_det,
-                (-m[3] * s5 + m[11] * s2 - m[15] * s1) * inv_det,
-                ( m[3] * s4 - m[7]  * s2 + m[15] * s0) * inv_det,
-                (-m[3] * s3 + m[7]  * s1 - m[11] * s0) * inv_det,
-                (-m[6] * s5 + m[10] * s4 - m[14] * s3) * inv_det,
-                ( m[2] * s5 - m[10] * s2 + m[14] * s1) * inv_det,
-                (-m[2] * s4 + m[6]  * s2 - m[14] * s0) * inv_det,
-                ( m[2] * s3 - m[6]  * s1 + m[10] * s0) * inv_det
+                 m[5] * c5 - m[9]  * c4 + m[13] * c3,
+                -m[1] * c5 + m[9]  * c2 - m[13] * c1,
+                 m[1] * c4 - m[5]  * c2 + m[13] * c0,
+                -m[1] * c3 + m[5]  * c1 - m[9]  * c0,
+                -m[4] * c5 + m[8]  * c4 - m[12] * c3,
+                 m[0] * c5 - m[8]  * c2 + m[12] * c1,
+                -m[0] * c4 + m[4]  * c2 - m[12] * c0,
+                 m[0] * c3 - m[4]  * c1 + m[8]  * c0,
+                 m[7] * s5 - m[11] * s4 + m[15] * s3,
+                -m[3] * s5 + m[11] * s2 - m[15] * s1,
+                 m[3] * s4 - m[7]  * s2 + m[15] * s0,
+                -m[3] * s3 + m[7]  * s1 - m[11] * s0,
+                -m[6] * s5 + m[10] * s4 - m[14] * s3,
+                 m[2] * s5 - m[10] * s2 + m[14] * s1,
+                -m[2] * s4 + m[6]  * s2 - m[14] * s0,
+                 m[2] * s3 - m[6]  * s1 + m[10] * s0
             ]
-        }
+        };
+
+        (adjugate, det)
     }
 }
 
@@ -315,32 +777,93 @@ impl Mul<Matrix> for Matrix {
 }
 
 mrusty_class!(Matrix, {
-    def!("initialize", |vec: Vec| {
+    def!("initialize", |mruby, vec: Vec| {
+        if vec.len() != 16 {
+            return mruby.raise("ArgumentError", "Array should contain 16 items");
+        }
+
         let mut array = [0.0f32; 16];
 
-        for i in 0..16 {
-            array[i] = vec[i].to_f64().unwrap() as f32;
+        for (i, value) in vec.iter().enumerate() {
+            array[i] = match value.to_f64() {
+                Ok(value) => value as f32,
+                Err(_) => return mruby.raise("TypeError", "Array should contain Floats")
+            };
         }
 
         Matrix::new(array)
     });
 
+    def_self!("from_a", |mruby, _slf: Value, vec: Vec| {
+        let values: Option<Vec<f32>> = vec.iter().map(|value| {
+            value.to_f64().ok().map(|value| value as f32)
+        }).collect();
+
+        match values {
+            Some(values) => match Matrix::try_from_slice(&values) {
+                Ok(matrix) => mruby.obj(matrix),
+                Err(message) => mruby.raise("ArgumentError", message)
+            },
+            None => mruby.raise("TypeError", "Array should contain Floats")
+        }
+    });
+
     def_self!("identity", |mruby, _slf: Value| {
         mruby.obj(Matrix::ident())
     });
 
+    def_self!("frustum", |mruby, _slf: Value, left: f64, right: f64, bottom: f64, top: f64,
+                                              near: f64, far: f64| {
+        let matrix = Matrix::frustum(left as f32, right as f32, bottom as f32, top as f32,
+                                     near as f32, far as f32);
+
+        mruby.obj(matrix)
+    });
+
+    def_self!("shear", |mruby, _slf: Value, xy: f64, xz: f64, yx: f64, yz: f64, zx: f64,
+                                            zy: f64| {
+        let matrix = Matrix::shear(xy as f32, xz as f32, yx as f32, yz as f32, zx as f32,
+                                   zy as f32);
+
+        mruby.obj(matrix)
+    });
+
+    def_self!("rotation", |mruby, _slf: Value, pitch: f64, yaw: f64, roll: f64| {
+        mruby.obj(Matrix::rotation(pitch as f32, yaw as f32, roll as f32))
+    });
+
+    def_self!("viewport", |mruby, _slf: Value, x: f64, y: f64, width: f64, height: f64| {
+        mruby.obj(Matrix::viewport(x as f32, y as f32, width as f32, height as f32))
+    });
+
+    def_self!("billboard", |mruby, _slf: Value, position: Value, camera_pos: Value, up: Value| {
+        let position = try_obj!(mruby, position, Vector);
+        let camera_pos = try_obj!(mruby, camera_pos, Vector);
+        let up = try_obj!(mruby, up, Vector);
+
+        mruby.obj(Matrix::billboard(position, camera_pos, up))
+    });
+
     def!("to_a", |mruby, slf: Matrix| {
         let vec: Vec<_> = slf.array.iter().map(|value| mruby.float(*value as f64)).collect();
 
         mruby.array(vec)
     });
 
-    def!("==", |mruby, slf: Matrix, other: Matrix| {
+    def!("==", |mruby, slf: Matrix, other: Value| {
+        let other = try_obj!(other, Matrix, else mruby.bool(false));
+
         let result = slf.array == other.array;
 
         mruby.bool(result)
     });
 
+    def!("approx_eq?", |mruby, slf: Matrix, other: Value, epsilon: f64| {
+        let other = try_obj!(mruby, other, Matrix);
+
+        mruby.bool(slf.approx_eq(other, epsilon as f32))
+    });
+
     def!("to_s", |mruby, slf: Matrix| {
         let string = format!("<Matrix: @array={:?}>", slf.array);
 
@@ -363,26 +886,90 @@ mrusty_class!(Matrix, {
         }
     });
 
-    def!("trans", |mruby, slf: Matrix, vector: Vector| {
-        mruby.obj(slf.trans((*vector).clone()))
+    def!("trans", |mruby, slf: Matrix, vector: Value| {
+        let vector = try_obj!(mruby, vector, Vector);
+
+        mruby.obj(slf.trans(vector))
     });
 
-    def!("scale", |mruby, slf: Matrix, vector: Vector| {
-        mruby.obj(slf.scale((*vector).clone()))
+    def!("scale", |mruby, slf: Matrix, vector: Value| {
+        let vector = try_obj!(mruby, vector, Vector);
+
+        mruby.obj(slf.scale(vector))
     });
 
-    def!("rot", |mruby, slf: Matrix, quaternion: Quaternion| {
-        mruby.obj(slf.rot((*quaternion).clone()))
+    def!("rot", |mruby, slf: Matrix, quaternion: Value| {
+        let quaternion = try_obj!(mruby, quaternion, Quaternion);
+
+        mruby.obj(slf.rot(quaternion))
     });
 
-    def!("rot_around", |mruby, slf: Matrix, quaternion: Quaternion,
-                                                point: Vector| {
-        mruby.obj(slf.rot_around((*quaternion).clone(), (*point).clone()))
+    def!("rot_around", |mruby, slf: Matrix, quaternion: Value, point: Value| {
+        let quaternion = try_obj!(mruby, quaternion, Quaternion);
+        let point = try_obj!(mruby, point, Vector);
+
+        mruby.obj(slf.rot_around(quaternion, point))
     });
 
     def!("inv", |mruby, slf: Matrix| {
         mruby.obj(slf.inv())
     });
+
+    def!("try_inv", |mruby, slf: Matrix| {
+        match slf.try_inv() {
+            Some(inverse) => mruby.obj(inverse),
+            None          => mruby.nil()
+        }
+    });
+
+    def!("project", |mruby, slf: Matrix, point: Value, x: f64, y: f64, width: f64,
+                     height: f64| {
+        let point = try_obj!(mruby, point, Vector);
+
+        let viewport = (x as f32, y as f32, width as f32, height as f32);
+
+        mruby.obj(slf.project(point, viewport))
+    });
+
+    def!("unproject", |mruby, slf: Matrix, point: Value, x: f64, y: f64, width: f64,
+                       height: f64| {
+        let point = try_obj!(mruby, point, Vector);
+
+        let viewport = (x as f32, y as f32, width as f32, height as f32);
+
+        match slf.unproject(point, viewport) {
+            Some(point) => mruby.obj(point),
+            None        => mruby.nil()
+        }
+    });
+
+    def!("trace", |mruby, slf: Matrix| {
+        mruby.float(slf.trace() as f64)
+    });
+
+    def!("adjugate", |mruby, slf: Matrix| {
+        mruby.obj(slf.adjugate())
+    });
+
+    def!("identity?", |mruby, slf: Matrix, epsilon: f64| {
+        mruby.bool(slf.is_identity(epsilon as f32))
+    });
+
+    def!("orthogonal?", |mruby, slf: Matrix, epsilon: f64| {
+        mruby.bool(slf.is_orthogonal(epsilon as f32))
+    });
+
+    def!("orthonormalize", |mruby, slf: Matrix| {
+        mruby.obj(slf.orthonormalize())
+    });
+
+    def!("finite?", |mruby, slf: Matrix| {
+        mruby.bool(slf.is_finite())
+    });
+
+    def!("nan?", |mruby, slf: Matrix| {
+        mruby.bool(slf.is_nan())
+    });
 });
 
 #[cfg(test)]
@@ -394,10 +981,117 @@ mod tests {
     use super::super::Quaternion;
 
     describe!(Matrix, (Vector, Quaternion), "
+      context 'when passed wrong types' do
+        it 'raises TypeError on #trans and #scale' do
+          expect { Matrix.identity.trans [1.0, 2.0, 3.0] }.to raise_error TypeError
+          expect { Matrix.identity.scale 2.0 }.to raise_error TypeError
+        end
+
+        it 'raises TypeError on #rot' do
+          expect { Matrix.identity.rot Vector.up }.to raise_error TypeError
+        end
+
+        it 'is not equal to other types on #==' do
+          expect(Matrix.identity == [1.0] * 16).to be false
+        end
+      end
+
+      context 'when from Array' do
+        it 'returns Matrix for 16 Floats on .from_a' do
+          expect(Matrix.from_a [0.0] * 16).to eql Matrix.new([0.0] * 16)
+        end
+
+        it 'raises ArgumentError for wrong length on .from_a' do
+          expect { Matrix.from_a [0.0] * 15 }.to raise_error ArgumentError
+        end
+
+        it 'raises TypeError for non-Float items on .from_a' do
+          expect { Matrix.from_a [0.0] * 15 + ['0.0'] }.to raise_error TypeError
+        end
+
+        it 'raises ArgumentError for wrong length on .new' do
+          expect { Matrix.new [0.0] * 15 }.to raise_error ArgumentError
+        end
+
+        it 'raises TypeError for non-Float items on .new' do
+          expect { Matrix.new [0.0] * 15 + [nil] }.to raise_error TypeError
+        end
+      end
+
+      context 'when viewport' do
+        subject { Matrix.viewport 0.0, 0.0, 800.0, 600.0 }
+
+        it 'maps lower NDC corner to window origin' do
+          expect(subject * Vector.new(-1.0, -1.0, -1.0)).to eql Vector.zero
+        end
+
+        it 'maps upper NDC corner to window size' do
+          expect(subject * Vector.one).to eql Vector.new 800.0, 600.0, 1.0
+        end
+      end
+
+      context 'when frustum' do
+        subject { Matrix.frustum -1.0, 1.0, -1.0, 1.0, 1.0, 3.0 }
+
+        it 'maps near plane corners to NDC corners' do
+          expect(subject * Vector.new(1.0, 1.0, -1.0)).to eql Vector.new 1.0, 1.0, -1.0
+        end
+      end
+
+      context 'when shear' do
+        subject { Matrix.shear 0.5, 0.0, 0.0, 0.0, 0.0, 2.0 }
+
+        it 'offsets axes on #*' do
+          expect(subject * Vector.one).to eql Vector.new 1.5, 1.0, 3.0
+        end
+      end
+
+      context 'when projecting' do
+        subject { Matrix.frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0) }
+
+        it 'maps to window coordinates on #project' do
+          expect(subject.project Vector.new(0.0, 0.0, -1.0), 0.0, 0.0, 800.0, 600.0).to eql(
+            Vector.new 400.0, 300.0, 0.0
+          )
+        end
+
+        it 'maps from window coordinates on #unproject' do
+          expect(subject.unproject Vector.new(400.0, 300.0, 0.0), 0.0, 0.0, 800.0, 600.0).to eql(
+            Vector.new 0.0, 0.0, -1.0
+          )
+        end
+
+        it 'returns nil for singular matrices on #unproject' do
+          expect(Matrix.new([0.0] * 16).unproject Vector.zero, 0.0, 0.0, 1.0, 1.0).to be_nil
+        end
+      end
+
+      context 'when billboard' do
+        subject { Matrix.billboard Vector.zero, Vector.new(0.0, 0.0, -2.0), Vector.up }
+
+        it 'faces the camera on #*' do
+          expect(subject * Vector.forward).to eql Vector.new(0.0, 0.0, -1.0)
+          expect(subject * Vector.up).to eql Vector.up
+        end
+      end
+
+      context 'when rotation' do
+        it 'returns identity for zero angles on .rotation' do
+          expect(Matrix.rotation 0.0, 0.0, 0.0).to eql Matrix.identity
+        end
+      end
+
       context 'when identity' do
         subject { Matrix.identity }
         let(:unit) { Vector.uniform 1.0 }
 
+        it 'compares rounding-perturbed copy on #approx_eq?' do
+          perturbed = Matrix.new subject.to_a.map { |value| value + 0.000001 }
+
+          expect(subject == perturbed).to be false
+          expect(subject.approx_eq? perturbed, 0.00001).to be true
+        end
+
         it 'converts to String on #to_s' do
           expect(subject.to_s).to eql(
             '<Matrix: @array=[1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1]>'
@@ -436,6 +1130,34 @@ mod tests {
         it 'computes inverse on #inv' do
           expect(subject.scale(Vector.uniform(2.0)).inv * unit).to eql Vector.uniform 0.5
         end
+
+        it 'sums the diagonal on #trace' do
+          expect(subject.trace).to eql 4.0
+        end
+
+        it 'computes adjugate on #adjugate' do
+          expect(subject.scale(Vector.uniform(2.0)).adjugate.trace).to eql 20.0
+        end
+
+        it 'is identity on #identity?' do
+          expect(subject.identity? 0.000001).to be true
+        end
+
+        it 'is orthogonal on #orthogonal?' do
+          expect(subject.orthogonal? 0.000001).to be true
+        end
+
+        it 'stays identity on #orthonormalize' do
+          expect(subject.orthonormalize).to eql subject
+        end
+
+        it 'is finite on #finite?' do
+          expect(subject.finite?).to be true
+        end
+
+        it 'is not NaN on #nan?' do
+          expect(subject.nan?).to be false
+        end
       end
     ");
 }
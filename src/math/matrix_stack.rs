@@ -0,0 +1,193 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use math::Matrix;
+
+/// A `struct` implementing the classic immediate-mode transform stack. (like `glPushMatrix`
+/// and `glPopMatrix`)
+///
+/// The stack starts with an identity matrix. `push` duplicates the top, `mul` right-multiplies
+/// it, so that transforms apply in the same order as with `Matrix`'s methods, and `pop` restores
+/// the previously pushed top.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Matrix;
+/// # use anima_engine::math::MatrixStack;
+/// # use anima_engine::math::Vector;
+/// let mut s = MatrixStack::new();
+///
+/// s.mul(Matrix::ident().trans(Vector::one()));
+/// s.push();
+/// s.mul(Matrix::ident().scale(Vector::new_unf(2.0)));
+///
+/// assert_eq!(s.top() * Vector::one(), Vector::new_unf(3.0));
+///
+/// s.pop();
+///
+/// assert_eq!(s.top() * Vector::one(), Vector::new_unf(2.0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixStack {
+    stack: Vec<Matrix>
+}
+
+impl MatrixStack {
+    /// Creates a stack containing only an identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::MatrixStack;
+    /// let s = MatrixStack::new();
+    ///
+    /// assert_eq!(s.top(), Matrix::ident());
+    /// assert_eq!(s.depth(), 1);
+    /// ```
+    pub fn new() -> MatrixStack {
+        MatrixStack {
+            stack: vec![Matrix::ident()]
+        }
+    }
+
+    /// Pushes a copy of the top matrix onto the stack.
+    pub fn push(&mut self) {
+        let top = self.top();
+
+        self.stack.push(top);
+    }
+
+    /// Pops the top matrix off the stack, restoring the one below it.
+    ///
+    /// # Panics
+    ///
+    /// Panics when popping the base matrix, i.e. more times than `push` was called.
+    pub fn pop(&mut self) {
+        if self.stack.len() == 1 {
+            panic!("Cannot pop the base matrix of a MatrixStack.");
+        }
+
+        self.stack.pop();
+    }
+
+    /// Right-multiplies the top matrix with `matrix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::MatrixStack;
+    /// # use anima_engine::math::Vector;
+    /// let mut s = MatrixStack::new();
+    /// let m = Matrix::ident().trans(Vector::one());
+    ///
+    /// s.mul(m);
+    /// s.mul(m);
+    ///
+    /// assert_eq!(s.top(), m * m);
+    /// ```
+    pub fn mul(&mut self, matrix: Matrix) {
+        let last = self.stack.len() - 1;
+
+        self.stack[last] = self.stack[last] * matrix;
+    }
+
+    /// Returns the top matrix.
+    pub fn top(&self) -> Matrix {
+        self.stack[self.stack.len() - 1]
+    }
+
+    /// Returns the number of matrices on the stack, which is always at least `1`.
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+}
+
+impl Default for MatrixStack {
+    fn default() -> MatrixStack {
+        MatrixStack::new()
+    }
+}
+
+use mrusty::*;
+
+// mruby objects cannot be mutated in place, so `push`, `pop` and `mul` return modified copies.
+mrusty_class!(MatrixStack, {
+    def!("initialize", || {
+        MatrixStack::new()
+    });
+
+    def!("push", |mruby, slf: MatrixStack| {
+        let mut stack = (*slf).clone();
+
+        stack.push();
+
+        mruby.obj(stack)
+    });
+
+    def!("pop", |mruby, slf: MatrixStack| {
+        if slf.depth() == 1 {
+            return mruby.raise("RuntimeError", "cannot pop the base Matrix");
+        }
+
+        let mut stack = (*slf).clone();
+
+        stack.pop();
+
+        mruby.obj(stack)
+    });
+
+    def!("mul", |mruby, slf: MatrixStack, matrix: Value| {
+        let matrix = try_obj!(mruby, matrix, Matrix);
+
+        let mut stack = (*slf).clone();
+
+        stack.mul(matrix);
+
+        mruby.obj(stack)
+    });
+
+    def!("top", |mruby, slf: MatrixStack| {
+        mruby.obj(slf.top())
+    });
+
+    def!("depth", |mruby, slf: MatrixStack| {
+        mruby.fixnum(slf.depth() as i32)
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::MatrixStack;
+    use super::super::Matrix;
+    use super::super::Vector;
+
+    describe!(MatrixStack, (Matrix, Vector), "
+      context 'when new' do
+        subject { MatrixStack.new }
+
+        it 'starts with identity on #top' do
+          expect(subject.top).to eql Matrix.identity
+          expect(subject.depth).to eql 1
+        end
+
+        it 'restores top on #pop' do
+          stack = subject.push.mul(Matrix.identity.trans(Vector.one)).pop
+
+          expect(stack.top).to eql Matrix.identity
+        end
+
+        it 'raises RuntimeError when popping the base on #pop' do
+          expect { subject.pop }.to raise_error RuntimeError
+        end
+      end
+    ");
+}
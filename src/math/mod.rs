@@ -7,22 +7,87 @@
 
 //! A `mod` containing linear math constructs useful in graphics.
 
+// Converts an mruby argument to a copy of the `$t` object it wraps, since mrusty panics on typed
+// custom arguments of the wrong class. Otherwise, it returns a raised `TypeError` from the
+// enclosing mruby method, or the `else` value if one is given.
+macro_rules! try_obj {
+    ( $value:ident, $t:ident, else $otherwise:expr ) => {
+        match $value.to_obj::<$t>() {
+            Ok(obj) => (*obj).clone(),
+            Err(_)  => return $otherwise
+        }
+    };
+    ( $mruby:ident, $value:ident, $t:ident ) => {
+        try_obj!($value, $t, else $mruby.raise("TypeError", concat!("expecting ", stringify!($t))))
+    };
+}
+
 mod vector;
 mod quaternion;
 mod matrix;
+mod cached_matrix;
+mod matrix_stack;
+mod transform;
+mod aabb;
+mod plane;
+mod ray;
+mod camera;
+mod frustum;
+mod spatial_hash;
 
 mod interpolate;
 mod interpolator;
+mod phased_interpolator;
+mod timeline;
 
+mod curve;
 mod bezier;
 
+mod color;
+
+mod scalar;
+
+mod spring;
+
 pub use self::vector::Vector;
+pub use self::vector::barycentric;
+pub use self::vector::try_barycentric;
+#[cfg(feature = "hash")]
+pub use self::vector::VectorKey;
 pub use self::quaternion::Quaternion;
+#[cfg(feature = "hash")]
+pub use self::quaternion::QuaternionKey;
 pub use self::matrix::Matrix;
+pub use self::cached_matrix::CachedMatrix;
+pub use self::matrix_stack::MatrixStack;
+pub use self::transform::Transform;
+pub use self::aabb::Aabb;
+pub use self::plane::Plane;
+pub use self::ray::Ray;
+pub use self::camera::Camera;
+pub use self::frustum::Frustum;
+pub use self::spatial_hash::SpatialHash;
 
 pub use self::interpolate::Interpolate;
 pub use self::interpolator::Interpolator;
 pub use self::interpolator::Behavior;
+pub use self::interpolator::ease;
+pub use self::phased_interpolator::PhasedInterpolator;
+pub use self::timeline::Timeline;
 
+pub use self::curve::Curve;
 pub use self::bezier::Bezier;
 pub use self::bezier::BezierPath;
+
+pub use self::color::Color;
+
+pub use self::scalar::Scalar;
+pub use self::scalar::smoothstep;
+pub use self::scalar::smootherstep;
+pub use self::scalar::wrap_angle;
+pub use self::scalar::lerp_angle;
+pub use self::scalar::inverse_lerp;
+pub use self::scalar::remap;
+pub use self::scalar::remap_clamped;
+
+pub use self::spring::Spring;
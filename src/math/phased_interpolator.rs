@@ -0,0 +1,180 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::time::Duration;
+
+use math::{self, Behavior};
+
+/// A `struct` useful for multi-phase motion, e.g. "ease in, hold, then ease out."
+///
+/// Phases are `(duration, Behavior)` pairs run one after another, starting at time `0.0`. The
+/// output ratio is distributed proportionally to the phases' durations: a phase taking a quarter
+/// of the total duration covers a quarter of the `0.0` to `1.0` range, shaped by its `Behavior`.
+/// This keeps the ratio continuous across phase boundaries.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Behavior;
+/// # use anima_engine::math::PhasedInterpolator;
+/// let i = PhasedInterpolator::new(vec![(1.0, Behavior::Acc), (3.0, Behavior::Linear)]);
+///
+/// assert_eq!(i.ratio(0.5), 0.0625);
+/// assert_eq!(i.ratio(1.0), 0.25);
+/// assert_eq!(i.ratio(2.5), 0.625);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhasedInterpolator {
+    /// `Vec<(f32, Behavior)>` of phases, each a duration with its `Behavior`
+    pub phases: Vec<(f32, Behavior)>
+}
+
+impl PhasedInterpolator {
+    /// Creates a phased interpolator from a list of `(duration, Behavior)` phases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Behavior;
+    /// # use anima_engine::math::PhasedInterpolator;
+    /// let i = PhasedInterpolator::new(vec![(1.0, Behavior::Linear)]);
+    ///
+    /// assert_eq!(i, PhasedInterpolator { phases: vec![(1.0, Behavior::Linear)] });
+    /// ```
+    pub fn new(phases: Vec<(f32, Behavior)>) -> PhasedInterpolator {
+        PhasedInterpolator {
+            phases: phases
+        }
+    }
+
+    /// Computes the combined duration of all phases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Behavior;
+    /// # use anima_engine::math::PhasedInterpolator;
+    /// let i = PhasedInterpolator::new(vec![(1.0, Behavior::Acc), (0.5, Behavior::Dec)]);
+    ///
+    /// assert_eq!(i.duration(), 1.5);
+    /// ```
+    pub fn duration(&self) -> f32 {
+        self.phases.iter().fold(0.0, |sum, &(duration, _)| sum + duration)
+    }
+
+    /// Computes the ratio (between `0.0` and `1.0`) for some given time. Times before the first
+    /// phase map to `0.0` and times after the last one map to `1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Behavior;
+    /// # use anima_engine::math::PhasedInterpolator;
+    /// let i = PhasedInterpolator::new(vec![(1.0, Behavior::Linear), (1.0, Behavior::Dec)]);
+    ///
+    /// assert_eq!(i.ratio(-1.0), 0.0);
+    /// assert_eq!(i.ratio(0.5), 0.25);
+    /// assert_eq!(i.ratio(1.5), 0.875);
+    /// assert_eq!(i.ratio(3.0), 1.0);
+    /// ```
+    pub fn ratio(&self, time: f32) -> f32 {
+        let total = self.duration();
+        let mut elapsed = 0.0;
+
+        for &(duration, behavior) in &self.phases {
+            if time < elapsed + duration {
+                let local = ((time - elapsed) / duration).max(0.0);
+
+                return (elapsed + duration * math::ease(behavior, local)) / total;
+            }
+
+            elapsed += duration;
+        }
+
+        1.0
+    }
+
+    /// Computes the ratio (between `0.0` and `1.0`) for some given elapsed `Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Behavior;
+    /// # use anima_engine::math::PhasedInterpolator;
+    /// # use std::time::Duration;
+    /// let i = PhasedInterpolator::new(vec![(2.0, Behavior::Linear)]);
+    ///
+    /// assert_eq!(i.ratio_dur(Duration::from_millis(500)), 0.25);
+    /// ```
+    pub fn ratio_dur(&self, elapsed: Duration) -> f32 {
+        self.ratio(elapsed.as_secs_f32())
+    }
+}
+
+use mrusty::*;
+
+mrusty_class!(PhasedInterpolator, {
+    def!("initialize", |mruby, phases: Vec| {
+        let mut result = Vec::with_capacity(phases.len());
+
+        for phase in phases {
+            let pair = match phase.to_vec() {
+                Ok(ref pair) if pair.len() == 2 && pair[0].class().to_str() == "Float" => {
+                    let behavior = pair[1].to_str().ok().and_then(|behavior| {
+                        behavior.parse::<Behavior>().ok()
+                    });
+
+                    behavior.map(|behavior| (pair[0].to_f64().unwrap() as f32, behavior))
+                }
+                _ => None
+            };
+
+            match pair {
+                Some(pair) => result.push(pair),
+                None       => {
+                    return mruby.raise("ArgumentError",
+                                       "Array should contain [Float, Symbol] pairs")
+                }
+            }
+        }
+
+        PhasedInterpolator::new(result)
+    });
+
+    def!("duration", |mruby, slf: PhasedInterpolator| {
+        mruby.float(slf.duration() as f64)
+    });
+
+    def!("ratio", |mruby, slf: PhasedInterpolator, time: f64| {
+        mruby.float(slf.ratio(time as f32) as f64)
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::PhasedInterpolator;
+
+    describe!(PhasedInterpolator, "
+      context 'when ease in, then linear' do
+        subject { PhasedInterpolator.new [[1.0, :acc], [3.0, :linear]] }
+
+        it 'returns combined duration on #duration' do
+          expect(subject.duration).to eql 4.0
+        end
+
+        it 'eases in during first phase on #ratio' do
+          expect(subject.ratio 0.5).to eql 0.0625
+        end
+
+        it 'reaches phase boundary proportionally on #ratio' do
+          expect(subject.ratio 1.0).to eql 0.25
+        end
+      end
+    ");
+}
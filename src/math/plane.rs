@@ -0,0 +1,158 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use math::Vector;
+
+/// A plane `struct` made of a unit normal and a distance, so that a point `p` lies on the plane
+/// when `normal.dot(p) + dist == 0.0`, e.g. the clip planes of a `Frustum`.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Plane;
+/// # use anima_engine::math::Vector;
+/// let p = Plane::new(Vector::up(), -1.0);
+///
+/// assert_eq!(p.signed_dist(Vector::new(3.0, 4.0, 5.0)), 3.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane {
+    /// `Vector` unit normal
+    pub normal: Vector,
+    /// `f32` signed distance of the origin from the plane
+    pub dist: f32
+}
+
+impl Plane {
+    /// Creates a plane using a (non-zero) normal and a distance. Both get scaled so that the
+    /// normal is a unit vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Plane;
+    /// # use anima_engine::math::Vector;
+    /// let p = Plane::new(Vector::new(0.0, 2.0, 0.0), -2.0);
+    ///
+    /// assert_eq!(p, Plane { normal: Vector::up(), dist: -1.0 });
+    /// ```
+    pub fn new(normal: Vector, dist: f32) -> Plane {
+        let len = normal.len();
+
+        Plane {
+            normal: normal * (1.0 / len),
+            dist: dist / len
+        }
+    }
+
+    /// Creates a plane passing through `point` using a (non-zero) normal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Plane;
+    /// # use anima_engine::math::Vector;
+    /// let p = Plane::from_point(Vector::up(), Vector::new(5.0, 1.0, 5.0));
+    ///
+    /// assert_eq!(p, Plane::new(Vector::up(), -1.0));
+    /// ```
+    pub fn from_point(normal: Vector, point: Vector) -> Plane {
+        let normal = normal.norm();
+
+        Plane {
+            normal: normal,
+            dist: -normal.dot(point)
+        }
+    }
+
+    /// Computes the signed distance of a point from the plane, positive on the side the normal
+    /// points to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Plane;
+    /// # use anima_engine::math::Vector;
+    /// let p = Plane::new(Vector::up(), 0.0);
+    ///
+    /// assert_eq!(p.signed_dist(Vector::up()), 1.0);
+    /// assert_eq!(p.signed_dist(-Vector::up()), -1.0);
+    /// ```
+    pub fn signed_dist(&self, point: Vector) -> f32 {
+        self.normal.dot(point) + self.dist
+    }
+}
+
+use mrusty::*;
+
+mrusty_class!(Plane, {
+    def!("initialize", |mruby, normal: Value, dist: f64| {
+        let normal = try_obj!(mruby, normal, Vector);
+
+        Plane::new(normal, dist as f32)
+    });
+
+    def_self!("from_point", |mruby, _slf: Value, normal: Value, point: Value| {
+        let normal = try_obj!(mruby, normal, Vector);
+        let point = try_obj!(mruby, point, Vector);
+
+        mruby.obj(Plane::from_point(normal, point))
+    });
+
+    def!("normal", |mruby, slf: Plane| {
+        mruby.obj(slf.normal)
+    });
+
+    def!("dist", |mruby, slf: Plane| {
+        mruby.float(slf.dist as f64)
+    });
+
+    def!("signed_dist", |mruby, slf: Plane, point: Value| {
+        let point = try_obj!(mruby, point, Vector);
+
+        mruby.float(slf.signed_dist(point) as f64)
+    });
+
+    def!("==", |mruby, slf: Plane, other: Value| {
+        let other = try_obj!(other, Plane, else mruby.bool(false));
+
+        let result = slf.normal == other.normal &&
+                     slf.dist == other.dist;
+
+        mruby.bool(result)
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::Plane;
+    use super::super::Vector;
+
+    describe!(Plane, (Vector), "
+      context 'when ground' do
+        subject { Plane.new Vector.up * 2.0, -2.0 }
+
+        it 'normalizes normal on #normal' do
+          expect(subject.normal).to eql Vector.up
+        end
+
+        it 'scales distance on #dist' do
+          expect(subject.dist).to eql -1.0
+        end
+
+        it 'computes signed distance on #signed_dist' do
+          expect(subject.signed_dist Vector.new(3.0, 4.0, 5.0)).to eql 3.0
+        end
+
+        it 'equals a plane from point on .from_point' do
+          expect(Plane.from_point Vector.up, Vector.up).to eql subject
+        end
+      end
+    ");
+}
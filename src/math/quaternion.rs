@@ -5,6 +5,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use std::f32::consts;
+
 use math::Vector;
 
 /// A simple quaterion `struct` tailored specifically for graphics.
@@ -107,6 +109,141 @@ impl Quaternion {
         Quaternion::new_rot(direction, angle)
     }
 
+    /// Creates a quaternion equivalent to the shortest rotation necessary to move the direction
+    /// `from` to the direction `to`, like `new_sph_rot`. Parallel directions produce the identity
+    /// and opposing directions produce a half-turn around an arbitrary perpendicular axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// let from = Vector::new(1.0, 1.0, 0.0);
+    /// let q = Quaternion::from_to_rotation(from, Vector::new(2.0, 2.0, 0.0));
+    ///
+    /// assert_eq!(q, Quaternion::ident());
+    ///
+    /// let q = Quaternion::from_to_rotation(from, -from);
+    ///
+    /// const EPSILON: f32 = 0.00001;
+    ///
+    /// assert!((from.rot(q) + from).len() < EPSILON);
+    /// ```
+    pub fn from_to_rotation(from: Vector, to: Vector) -> Quaternion {
+        const EPSILON: f32 = 0.000001;
+
+        let from = from.norm();
+        let to = to.norm();
+        let dot = from.dot(to).max(-1.0).min(1.0);
+
+        if dot >= 1.0 - EPSILON {
+            return Quaternion::ident();
+        }
+
+        if dot <= -1.0 + EPSILON {
+            let axis = if from.x.abs() < 0.9 {
+                from.cross(Vector::new(1.0, 0.0, 0.0))
+            } else {
+                from.cross(Vector::new(0.0, 1.0, 0.0))
+            };
+
+            return Quaternion::new_rot(axis, consts::PI);
+        }
+
+        Quaternion::new_rot(to.cross(from), dot.acos())
+    }
+
+    /// Creates a quaternion rotating the default axes onto a basis, i.e. such that
+    /// `Vector::right().rot(q) == right`, `Vector::up().rot(q) == up` and
+    /// `Vector::forward().rot(q) == forward`.
+    ///
+    /// The basis must be orthonormal and keep the handedness of the default axes
+    /// (`up.cross(forward) == -right`, just like `Vector::up().cross(Vector::forward())` is
+    /// `Vector::left()`), otherwise it is not a rotation and the result is meaningless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// let q = Quaternion::from_basis(Vector::forward(), Vector::up(), Vector::left());
+    /// let r = Vector::right().rot(q);
+    ///
+    /// assert!((r - Vector::forward()).len() < 0.00001);
+    /// assert_eq!(Quaternion::from_basis(Vector::right(), Vector::up(), Vector::forward()),
+    ///            Quaternion::ident());
+    /// ```
+    pub fn from_basis(right: Vector, up: Vector, forward: Vector) -> Quaternion {
+        Quaternion::from_columns(-right, up, forward)
+    }
+
+    // Converts the rotation matrix with columns `c0`, `c1` and `c2` (the images of the x, y and z
+    // axes under `Vector::rot`) to a quaternion, branching on the largest diagonal term to keep
+    // the square roots well away from zero. (Shepperd's method)
+    fn from_columns(c0: Vector, c1: Vector, c2: Vector) -> Quaternion {
+        let trace = c0.x + c1.y + c2.z;
+
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+
+            Quaternion {
+                x: (c2.y - c1.z) / s,
+                y: (c0.z - c2.x) / s,
+                z: (c1.x - c0.y) / s,
+                w: s / 4.0
+            }
+        } else if c0.x > c1.y && c0.x > c2.z {
+            let s = (1.0 + c0.x - c1.y - c2.z).sqrt() * 2.0;
+
+            Quaternion {
+                x: s / 4.0,
+                y: (c1.x + c0.y) / s,
+                z: (c2.x + c0.z) / s,
+                w: (c2.y - c1.z) / s
+            }
+        } else if c1.y > c2.z {
+            let s = (1.0 + c1.y - c0.x - c2.z).sqrt() * 2.0;
+
+            Quaternion {
+                x: (c1.x + c0.y) / s,
+                y: s / 4.0,
+                z: (c2.y + c1.z) / s,
+                w: (c0.z - c2.x) / s
+            }
+        } else {
+            let s = (1.0 + c2.z - c0.x - c1.y).sqrt() * 2.0;
+
+            Quaternion {
+                x: (c2.x + c0.z) / s,
+                y: (c2.y + c1.z) / s,
+                z: s / 4.0,
+                w: (c1.x - c0.y) / s
+            }
+        }
+    }
+
+    /// Creates a quaternion from Euler angles in radians: `pitch` around *x*, `yaw` around *y*
+    /// and `roll` around *z*. Applied the way `Matrix::rot` applies rotations, roll comes first,
+    /// then pitch, then yaw. (`yaw * pitch * roll` as matrices)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// # use std::f32::consts;
+    /// let q = Quaternion::from_euler(0.0, consts::PI / 2.0, 0.0);
+    /// let v = Matrix::ident().rot(q) * Vector::forward();
+    ///
+    /// assert!(v.dist(Vector::left()) < 0.00001);
+    /// ```
+    pub fn from_euler(pitch: f32, yaw: f32, roll: f32) -> Quaternion {
+        Quaternion::new_rot(Vector::forward(), roll) *
+        Quaternion::new_rot(Vector::left(), pitch) *
+        Quaternion::new_rot(Vector::up(), yaw)
+    }
+
     /// Creates an identity (0.0, 0.0, 0.0, 1.0) quaternion.
     ///
     /// # Examples
@@ -180,6 +317,10 @@ impl Quaternion {
 
     /// Computes the angle in radians between two quaternions.
     ///
+    /// The angle is not corrected for the sign of the quaternions, so it can report the long way
+    /// around (up to `2π`), and rounding can produce `NaN` for nearly-identical quaternions. Use
+    /// `angle_to` for the shortest angular distance.
+    ///
     /// # Examples
     ///
     /// ```
@@ -193,9 +334,270 @@ impl Quaternion {
     pub fn angle(&self, other: Quaternion) -> f32 {
         self.dot(other).acos() * 2.0
     }
+
+    /// Computes the shortest angle in radians between the rotations represented by two
+    /// quaternions. The result is always between `0.0` and `π`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// # use std::f32::consts;
+    /// let q = Quaternion::new_rot(Vector::up(), consts::PI * 3.0 / 2.0);
+    ///
+    /// const EPSILON: f32 = 0.00001;
+    ///
+    /// assert!((Quaternion::ident().angle_to(q) - consts::PI / 2.0).abs() < EPSILON);
+    /// ```
+    pub fn angle_to(&self, other: Quaternion) -> f32 {
+        self.dot(other).abs().min(1.0).acos() * 2.0
+    }
+
+    /// Converts a unit quaternion to the axis and angle in radians of its rotation, the inverse
+    /// of `new_rot`. The angle is between `0.0` and `2π`; rotations by exactly `0.0` have no
+    /// meaningful axis and return `Vector::left()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// let (axis, angle) = Quaternion::new_rot(Vector::up(), 1.5).to_axis_angle();
+    ///
+    /// assert!(axis.dist(Vector::up()) < 0.00001);
+    /// assert!((angle - 1.5).abs() < 0.00001);
+    /// ```
+    pub fn to_axis_angle(&self) -> (Vector, f32) {
+        // atan2 stays accurate for small angles, where acos(w) loses most of its precision.
+        let sin = Vector::new(self.x, self.y, self.z).len();
+
+        if sin == 0.0 {
+            (Vector::left(), 0.0)
+        } else {
+            (Vector::new(self.x / sin, self.y / sin, self.z / sin), sin.atan2(self.w) * 2.0)
+        }
+    }
+
+    /// Computes the axis and shortest angle in radians of the rotation taking a unit
+    /// quaternion to `other`, i.e. `to_axis_angle` of `other * self.inv()`, corrected so that
+    /// the angle is between `0.0` and `π` like `angle_to`. Dividing the angle by the time
+    /// between two frames gives the angular velocity around the axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// # use std::f32::consts;
+    /// let q1 = Quaternion::new_rot(Vector::up(), 0.5);
+    /// let q2 = Quaternion::new_rot(Vector::up(), 0.5 - consts::PI / 2.0);
+    ///
+    /// let (axis, angle) = q1.delta_axis_angle(q2);
+    ///
+    /// assert!(axis.dist(-Vector::up()) < 0.0001);
+    /// assert!((angle - consts::PI / 2.0).abs() < 0.0001);
+    /// ```
+    pub fn delta_axis_angle(&self, other: Quaternion) -> (Vector, f32) {
+        (other * self.inv()).canonicalize().to_axis_angle()
+    }
+
+    /// Rotates a unit quaternion towards `target` by at most `max_radians`, snapping to `target`
+    /// when it is within range. The rotation always takes the shortest path, like `angle_to`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// # use std::f32::consts;
+    /// let q = Quaternion::new_rot(Vector::up(), consts::PI / 2.0);
+    ///
+    /// let step = Quaternion::ident().rotate_towards(q, 0.5);
+    ///
+    /// assert!((step.angle_to(Quaternion::ident()) - 0.5).abs() < 0.0001);
+    /// assert_eq!(Quaternion::ident().rotate_towards(q, consts::PI), q);
+    /// ```
+    pub fn rotate_towards(&self, target: Quaternion, max_radians: f32) -> Quaternion {
+        let angle = self.angle_to(target);
+
+        if angle <= max_radians {
+            target
+        } else {
+            self.interpolate(target, max_radians.max(0.0) / angle)
+        }
+    }
+
+    /// Linearly interpolates the components of two quaternions, without normalizing the result.
+    ///
+    /// This is the cheapest blend, but the result is shorter than a unit quaternion in between
+    /// the endpoints, so it only represents a pure rotation after normalization. It is meant for
+    /// accumulating weighted blends that get normalized once at the end; use `nlerp` for very
+    /// close rotations and `interpolate` (slerp) when constant angular speed matters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// let q1 = Quaternion::new(1.0, 0.0, 0.0, 0.0);
+    /// let q2 = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(q1.lerp(q2, 0.5), Quaternion::new(0.5, 0.5, 0.0, 0.0));
+    /// ```
+    pub fn lerp(&self, other: Quaternion, ratio: f32) -> Quaternion {
+        Quaternion {
+            x: self.x * (1.0 - ratio) + other.x * ratio,
+            y: self.y * (1.0 - ratio) + other.y * ratio,
+            z: self.z * (1.0 - ratio) + other.z * ratio,
+            w: self.w * (1.0 - ratio) + other.w * ratio
+        }
+    }
+
+    /// Linearly interpolates two quaternions along the shortest path and normalizes the result.
+    ///
+    /// The result is always a unit quaternion, but its angular speed is not constant. This is
+    /// cheaper than `interpolate` (slerp) and indistinguishable from it for small angles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// let q1 = Quaternion::new(1.0, 0.0, 0.0, 0.0);
+    /// let q2 = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    ///
+    /// let q = q1.nlerp(q2, 0.5);
+    ///
+    /// assert!((q.dot(q) - 1.0).abs() < 0.00001);
+    /// ```
+    pub fn nlerp(&self, other: Quaternion, ratio: f32) -> Quaternion {
+        let other = if self.dot(other) < 0.0 { -other } else { other };
+
+        let result = self.lerp(other, ratio);
+        let len = result.dot(result).sqrt();
+
+        Quaternion {
+            x: result.x / len,
+            y: result.y / len,
+            z: result.z / len,
+            w: result.w / len
+        }
+    }
+
+    /// Rotates a slice of points according to the rotation represented by a (unit) quaternion.
+    ///
+    /// `Vector::rot` does two quaternion multiplications per point, while this converts the
+    /// quaternion to a rotation matrix once and then only needs 9 multiplications per point,
+    /// which pays off for whole vertex buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// let q = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    /// let points = [Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 1.0)];
+    ///
+    /// assert_eq!(q.rotate_points(&points), vec![
+    ///     Vector::new(-1.0, 0.0, 0.0),
+    ///     Vector::new(0.0, 1.0, -1.0)
+    /// ]);
+    /// ```
+    pub fn rotate_points(&self, points: &[Vector]) -> Vec<Vector> {
+        let (x, y, z) = self.rotation_columns();
+
+        points.iter().map(|p| x * p.x + y * p.y + z * p.z).collect()
+    }
+
+    /// Rotates a slice of points in place like `rotate_points`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// let q = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    /// let mut points = [Vector::new(1.0, 0.0, 0.0)];
+    ///
+    /// q.rotate_points_mut(&mut points);
+    ///
+    /// assert_eq!(points, [Vector::new(-1.0, 0.0, 0.0)]);
+    /// ```
+    pub fn rotate_points_mut(&self, points: &mut [Vector]) {
+        let (x, y, z) = self.rotation_columns();
+
+        for p in points.iter_mut() {
+            *p = x * p.x + y * p.y + z * p.z;
+        }
+    }
+
+    // Computes the columns of the rotation matrix equivalent to `Vector::rot`.
+    fn rotation_columns(&self) -> (Vector, Vector, Vector) {
+        let q = self;
+
+        (
+            Vector::new(1.0 - 2.0 * (q.y * q.y + q.z * q.z),
+                        2.0 * (q.x * q.y - q.z * q.w),
+                        2.0 * (q.x * q.z + q.y * q.w)),
+            Vector::new(2.0 * (q.x * q.y + q.z * q.w),
+                        1.0 - 2.0 * (q.x * q.x + q.z * q.z),
+                        2.0 * (q.y * q.z - q.x * q.w)),
+            Vector::new(2.0 * (q.x * q.z - q.y * q.w),
+                        2.0 * (q.y * q.z + q.x * q.w),
+                        1.0 - 2.0 * (q.x * q.x + q.y * q.y))
+        )
+    }
+
+    /// Checks whether all components of a quaternion are finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// assert!(Quaternion::ident().is_finite());
+    /// assert!(!Quaternion::new(0.0, 0.0, 0.0, 1.0 / 0.0).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// Checks whether any component of a quaternion is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// assert!(!Quaternion::ident().is_nan());
+    /// assert!(Quaternion::new(0.0, 0.0, 0.0, 0.0).inv().is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
+
+    /// Computes the quaternion representing the same rotation in the canonical hemisphere
+    /// (`w >= 0.0`), negating it when `w < 0.0`, since `q` and `-q` represent the same rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Quaternion;
+    /// let q = Quaternion::new(0.5, -0.5, 0.5, -0.5);
+    ///
+    /// assert_eq!(q.canonicalize(), Quaternion::new(-0.5, 0.5, -0.5, 0.5));
+    /// assert_eq!(Quaternion::ident().canonicalize(), Quaternion::ident());
+    /// ```
+    pub fn canonicalize(&self) -> Quaternion {
+        if self.w < 0.0 {
+            -*self
+        } else {
+            *self
+        }
+    }
 }
 
 use std::ops::Mul;
+use std::ops::Neg;
+#[cfg(feature = "hash")]
+use std::hash::{Hash, Hasher};
 
 use mrusty::*;
 
@@ -214,14 +616,75 @@ impl Mul for Quaternion {
     }
 }
 
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Quaternion {
+        Quaternion {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w
+        }
+    }
+}
+
+/// A `Quaternion` wrapper useful as an exact-match `HashMap` or `HashSet` key. Only available with
+/// the `hash` feature.
+///
+/// Keys compare and hash bit patterns with the same caveats as `VectorKey`. Note that `q` and `-q`
+/// represent the same rotation but are different keys; use `canonicalize` first if that matters.
+#[cfg(feature = "hash")]
+#[derive(Clone, Copy, Debug)]
+pub struct QuaternionKey(pub Quaternion);
+
+#[cfg(feature = "hash")]
+impl QuaternionKey {
+    fn bits(&self) -> [u32; 4] {
+        let q = self.0;
+
+        [(q.x + 0.0).to_bits(), (q.y + 0.0).to_bits(), (q.z + 0.0).to_bits(), (q.w + 0.0).to_bits()]
+    }
+}
+
+#[cfg(feature = "hash")]
+impl PartialEq for QuaternionKey {
+    fn eq(&self, other: &QuaternionKey) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+#[cfg(feature = "hash")]
+impl Eq for QuaternionKey {}
+
+#[cfg(feature = "hash")]
+impl Hash for QuaternionKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+
 impl Interpolate for Quaternion {
     fn interpolate(&self, other: Quaternion, ratio: f32) -> Quaternion {
-        let cos_htheta = self.dot(other);
+        const EPSILON: f32 = 0.0001;
+
+        let mut cos_htheta = self.dot(other);
+        let mut other = other;
+
+        // q and -q represent the same rotation; take the one closer to self for the shortest path.
+        if cos_htheta < 0.0 {
+            cos_htheta = -cos_htheta;
+            other = -other;
+        }
+
+        // Nearly identical rotations make sin(θ/2) vanish; fall back to normalized lerp.
+        if cos_htheta > 1.0 - EPSILON {
+            return self.nlerp(other, ratio);
+        }
+
         let htheta = cos_htheta.acos();
         let sin_htheta = htheta.sin();
 
-        if sin_htheta == 0.0 { panic!("Cannot interpolate between two opposing rotations."); }
-
         let ratio1 = ((1.0 - ratio) * htheta).sin() / sin_htheta;
         let ratio2 = (ratio * htheta).sin() / sin_htheta;
 
@@ -235,22 +698,79 @@ impl Interpolate for Quaternion {
 }
 
 mrusty_class!(Quaternion, {
-    def!("initialize", |x: f64, y: f64, z: f64, w: f64| {
-        Quaternion::new(x as f32, y as f32, z as f32, w as f32)
+    // Quaternion.new(x, y, z, w) or Quaternion.new(axis, angle)
+    def!("initialize", |mruby; args| {
+        let message = "expecting 4 Floats or a Vector axis and a Float angle";
+
+        // Accepts Fixnums as well, like typed f64 arguments do.
+        let number = |value: &Value| {
+            value.to_f64().or_else(|_| value.to_i32().map(|value| value as f64))
+        };
+
+        match args.len() {
+            4 => {
+                match (number(&args[0]), number(&args[1]), number(&args[2]), number(&args[3])) {
+                    (Ok(x), Ok(y), Ok(z), Ok(w)) => {
+                        Quaternion::new(x as f32, y as f32, z as f32, w as f32)
+                    }
+                    _ => return mruby.raise("ArgumentError", message)
+                }
+            }
+            2 => {
+                match (args[0].to_obj::<Vector>(), number(&args[1])) {
+                    (Ok(axis), Ok(angle)) => Quaternion::new_rot((*axis).clone(), angle as f32),
+                    _ => return mruby.raise("ArgumentError", message)
+                }
+            }
+            _ => return mruby.raise("ArgumentError", message)
+        }
     });
 
-    def_self!("rotation", |mruby, _slf: Value, direction: Vector, angle: f64| {
-        let quaternion = Quaternion::new_rot((*direction).clone(), angle as f32);
+    def_self!("rotation", |mruby, _slf: Value, direction: Value, angle: f64| {
+        let direction = try_obj!(mruby, direction, Vector);
+
+        let quaternion = Quaternion::new_rot(direction, angle as f32);
 
         mruby.obj(quaternion)
     });
 
-    def_self!("sph_rotation", |mruby, _slf: Value, start: Vector, finish: Vector| {
-        let quaternion = Quaternion::new_sph_rot((*start).clone(), (*finish).clone());
+    // Same as rotation, named after the axis-angle form.
+    def_self!("from_axis_angle", |mruby, _slf: Value, axis: Value, angle: f64| {
+        let axis = try_obj!(mruby, axis, Vector);
+
+        mruby.obj(Quaternion::new_rot(axis, angle as f32))
+    });
+
+    def_self!("sph_rotation", |mruby, _slf: Value, start: Value, finish: Value| {
+        let start = try_obj!(mruby, start, Vector);
+        let finish = try_obj!(mruby, finish, Vector);
+
+        let quaternion = Quaternion::new_sph_rot(start, finish);
+
+        mruby.obj(quaternion)
+    });
+
+    def_self!("from_to_rotation", |mruby, _slf: Value, from: Value, to: Value| {
+        let from = try_obj!(mruby, from, Vector);
+        let to = try_obj!(mruby, to, Vector);
+
+        let quaternion = Quaternion::from_to_rotation(from, to);
 
         mruby.obj(quaternion)
     });
 
+    def_self!("from_euler", |mruby, _slf: Value, pitch: f64, yaw: f64, roll: f64| {
+        mruby.obj(Quaternion::from_euler(pitch as f32, yaw as f32, roll as f32))
+    });
+
+    def_self!("from_basis", |mruby, _slf: Value, right: Value, up: Value, forward: Value| {
+        let right = try_obj!(mruby, right, Vector);
+        let up = try_obj!(mruby, up, Vector);
+        let forward = try_obj!(mruby, forward, Vector);
+
+        mruby.obj(Quaternion::from_basis(right, up, forward))
+    });
+
     def_self!("identity", |mruby, _slf: Value| {
         mruby.obj(Quaternion::ident())
     });
@@ -271,7 +791,9 @@ mrusty_class!(Quaternion, {
         mruby.float(slf.w as f64)
     });
 
-    def!("==", |mruby, slf: Quaternion, other: Quaternion| {
+    def!("==", |mruby, slf: Quaternion, other: Value| {
+        let other = try_obj!(other, Quaternion, else mruby.bool(false));
+
         let result = slf.x == other.x &&
                      slf.y == other.y &&
                      slf.z == other.z &&
@@ -287,8 +809,18 @@ mrusty_class!(Quaternion, {
         mruby.string(&string)
     });
 
-    def!("*", |mruby, slf: Quaternion, other: Quaternion| {
-        mruby.obj((*slf).clone() * (*other).clone())
+    def!("*", |mruby, slf: Quaternion, other: Value| {
+        let other = try_obj!(mruby, other, Quaternion);
+
+        mruby.obj((*slf).clone() * other)
+    });
+
+    def!("-@", |mruby, slf: Quaternion| {
+        mruby.obj(-(*slf).clone())
+    });
+
+    def!("canonicalize", |mruby, slf: Quaternion| {
+        mruby.obj(slf.canonicalize())
     });
 
     def!("conj", |mruby, slf: Quaternion| {
@@ -299,16 +831,83 @@ mrusty_class!(Quaternion, {
         mruby.obj(slf.inv())
     });
 
-    def!("dot", |mruby, slf: Quaternion, other: Quaternion| {
-        mruby.float(slf.dot((*other).clone()) as f64)
+    def!("dot", |mruby, slf: Quaternion, other: Value| {
+        let other = try_obj!(mruby, other, Quaternion);
+
+        mruby.float(slf.dot(other) as f64)
+    });
+
+    def!("angle", |mruby, slf: Quaternion, other: Value| {
+        let other = try_obj!(mruby, other, Quaternion);
+
+        mruby.float(slf.angle(other) as f64)
+    });
+
+    def!("angle_to", |mruby, slf: Quaternion, other: Value| {
+        let other = try_obj!(mruby, other, Quaternion);
+
+        mruby.float(slf.angle_to(other) as f64)
+    });
+
+    def!("to_axis_angle", |mruby, slf: Quaternion| {
+        let (axis, angle) = slf.to_axis_angle();
+
+        mruby.array(vec![mruby.obj(axis), mruby.float(angle as f64)])
+    });
+
+    def!("delta_axis_angle", |mruby, slf: Quaternion, other: Value| {
+        let other = try_obj!(mruby, other, Quaternion);
+
+        let (axis, angle) = slf.delta_axis_angle(other);
+
+        mruby.array(vec![mruby.obj(axis), mruby.float(angle as f64)])
     });
 
-    def!("angle", |mruby, slf: Quaternion, other: Quaternion| {
-        mruby.float(slf.angle((*other).clone()) as f64)
+    def!("rotate_towards", |mruby, slf: Quaternion, target: Value, max_radians: f64| {
+        let target = try_obj!(mruby, target, Quaternion);
+
+        mruby.obj(slf.rotate_towards(target, max_radians as f32))
     });
 
-    def!("interpolate", |mruby, slf: Quaternion, other: Quaternion, ratio: f64| {
-        mruby.obj(slf.interpolate((*other).clone(), ratio as f32))
+    def!("finite?", |mruby, slf: Quaternion| {
+        mruby.bool(slf.is_finite())
+    });
+
+    def!("nan?", |mruby, slf: Quaternion| {
+        mruby.bool(slf.is_nan())
+    });
+
+    def!("rotate_points", |mruby, slf: Quaternion, points: Vec| {
+        let mut vectors = Vec::with_capacity(points.len());
+
+        for point in points {
+            match point.to_obj::<Vector>() {
+                Ok(vector) => vectors.push((*vector).clone()),
+                Err(_)     => return mruby.raise("TypeError", "expecting Array of Vectors")
+            }
+        }
+
+        let rotated = slf.rotate_points(&vectors).into_iter().map(|v| mruby.obj(v)).collect();
+
+        mruby.array(rotated)
+    });
+
+    def!("lerp", |mruby, slf: Quaternion, other: Value, ratio: f64| {
+        let other = try_obj!(mruby, other, Quaternion);
+
+        mruby.obj(slf.lerp(other, ratio as f32))
+    });
+
+    def!("nlerp", |mruby, slf: Quaternion, other: Value, ratio: f64| {
+        let other = try_obj!(mruby, other, Quaternion);
+
+        mruby.obj(slf.nlerp(other, ratio as f32))
+    });
+
+    def!("interpolate", |mruby, slf: Quaternion, other: Value, ratio: f64| {
+        let other = try_obj!(mruby, other, Quaternion);
+
+        mruby.obj(slf.interpolate(other, ratio as f32))
     });
 });
 
@@ -320,6 +919,44 @@ mod tests {
     use super::super::Vector;
 
     describe!(Quaternion, (Vector), "
+      context 'when passed wrong types' do
+        it 'raises TypeError on .rotation' do
+          expect { Quaternion.rotation [0.0, 1.0, 0.0], 1.0 }.to raise_error TypeError
+        end
+
+        it 'raises TypeError on #angle_to' do
+          expect { Quaternion.identity.angle_to Vector.up }.to raise_error TypeError
+        end
+      end
+
+      context 'when initialized' do
+        it 'creates from components on .new' do
+          expect(Quaternion.new 0.0, 0.0, 0.0, 1.0).to eql Quaternion.identity
+        end
+
+        it 'creates from integer components on .new' do
+          expect(Quaternion.new 0, 0, 0, 1).to eql Quaternion.identity
+        end
+
+        it 'creates from axis and integer angle on .new' do
+          expect(Quaternion.new Vector.up, 1).to eql Quaternion.rotation(Vector.up, 1)
+        end
+
+        it 'creates from axis and angle on .new' do
+          expect(Quaternion.new Vector.up, 1.0).to eql Quaternion.rotation(Vector.up, 1.0)
+        end
+
+        it 'creates from axis and angle on .from_axis_angle' do
+          quaternion = Quaternion.from_axis_angle Vector.up, 1.0
+
+          expect(quaternion).to eql Quaternion.rotation(Vector.up, 1.0)
+        end
+
+        it 'raises ArgumentError on anything else on .new' do
+          expect { Quaternion.new 1.0, 2.0 }.to raise_error ArgumentError
+        end
+      end
+
       context 'when roation' do
         subject { Quaternion.rotation(Vector.up, Math::PI / 2) }
         let(:second) { Quaternion.sph_rotation(Vector.forward, Vector.right) }
@@ -344,6 +981,36 @@ mod tests {
           expect(subject.angle Quaternion.identity).to be_within(0.000001).of Math::PI / 2
         end
 
+        it 'computes shortest angle on #angle_to' do
+          long = Quaternion.rotation(Vector.up, Math::PI * 3 / 2)
+
+          expect(long.angle_to Quaternion.identity).to be_within(0.000001).of Math::PI / 2
+        end
+
+        it 'returns axis and angle on #to_axis_angle' do
+          axis, angle = subject.to_axis_angle
+
+          expect(axis.dist Vector.up).to be_within(0.0001).of 0.0
+          expect(angle).to be_within(0.0001).of Math::PI / 2
+        end
+
+        it 'returns relative axis and angle on #delta_axis_angle' do
+          axis, angle = Quaternion.identity.delta_axis_angle subject
+
+          expect(axis.dist Vector.up).to be_within(0.0001).of 0.0
+          expect(angle).to be_within(0.0001).of Math::PI / 2
+        end
+
+        it 'rotates by at most max radians on #rotate_towards' do
+          rotated = Quaternion.identity.rotate_towards(subject, Math::PI / 8)
+
+          expect(rotated.angle_to Quaternion.identity).to be_within(0.0001).of Math::PI / 8
+        end
+
+        it 'snaps to target in range on #rotate_towards' do
+          expect(Quaternion.identity.rotate_towards subject, Math::PI).to eql subject
+        end
+
         it 'interpolates on #interpolate' do
           interpolated = subject.interpolate(Quaternion.rotation(Vector.up, Math::PI), 0.5)
           correct = Quaternion.rotation(Vector.up, Math::PI * 3 / 4)
@@ -353,6 +1020,41 @@ mod tests {
           expect(interpolated.z).to be_within(0.001).of correct.z
           expect(interpolated.w).to be_within(0.001).of correct.w
         end
+
+        it 'rotates like Vector#rot on #rotate_points' do
+          rotated = subject.rotate_points([Vector.forward, Vector.up])
+
+          expect((rotated[0] - Vector.forward.rot(subject)).len).to be_within(0.00001).of 0.0
+          expect((rotated[1] - Vector.up.rot(subject)).len).to be_within(0.00001).of 0.0
+        end
+
+        it 'raises TypeError for non-Vectors on #rotate_points' do
+          expect { subject.rotate_points [1.0] }.to raise_error TypeError
+        end
+      end
+
+      context 'when rotating between directions' do
+        it 'returns identity for parallel directions on .from_to_rotation' do
+          rotation = Quaternion.from_to_rotation(Vector.forward, Vector.forward * 2.0)
+
+          expect(rotation).to eql Quaternion.identity
+        end
+
+        it 'returns a half-turn for opposing directions on .from_to_rotation' do
+          rotated = Vector.forward.rot Quaternion.from_to_rotation(Vector.forward, Vector.back)
+
+          expect(rotated.x).to be_within(0.000001).of 0.0
+          expect(rotated.y).to be_within(0.000001).of 0.0
+          expect(rotated.z).to be_within(0.000001).of -1.0
+        end
+      end
+
+      context 'when from basis' do
+        it 'returns identity for the default axes on .from_basis' do
+          expect(Quaternion.from_basis Vector.right, Vector.up, Vector.forward).to eql(
+            Quaternion.identity
+          )
+        end
       end
 
       context 'when unit' do
@@ -386,6 +1088,15 @@ mod tests {
           expect(subject.conj).to eql Quaternion.new -1.0, -1.0, -1.0, 1.0
         end
 
+        it 'negates on #-@' do
+          expect(-subject).to eql Quaternion.new -1.0, -1.0, -1.0, -1.0
+        end
+
+        it 'canonicalizes on #canonicalize' do
+          expect(subject.canonicalize).to eql subject
+          expect((-subject).canonicalize).to eql subject
+        end
+
         it 'computes dot product on #dot' do
           expect(subject.dot subject).to eql 4.0
         end
@@ -393,6 +1104,24 @@ mod tests {
         it 'multiplies quaternion on #*' do
           expect(subject * Quaternion.identity).to eql subject
         end
+
+        it 'interpolates components on #lerp' do
+          expect(subject.lerp Quaternion.new(3.0, 3.0, 3.0, 3.0), 0.5).to eql(
+            Quaternion.new 2.0, 2.0, 2.0, 2.0
+          )
+        end
+
+        it 'normalizes on #nlerp' do
+          expect(subject.nlerp subject, 0.5).to eql Quaternion.new 0.5, 0.5, 0.5, 0.5
+        end
+
+        it 'is finite on #finite?' do
+          expect(subject.finite?).to be true
+        end
+
+        it 'is not NaN on #nan?' do
+          expect(subject.nan?).to be false
+        end
       end
     ");
 }
@@ -0,0 +1,121 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use math::Vector;
+
+/// A half-line `struct` starting at an origin and going along a unit direction.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Ray;
+/// # use anima_engine::math::Vector;
+/// let r = Ray::new(Vector::zero(), Vector::new(0.0, 0.0, 2.0));
+///
+/// assert_eq!(r.at(3.0), Vector::new(0.0, 0.0, 3.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray {
+    /// `Vector` starting point
+    pub origin: Vector,
+    /// `Vector` unit direction
+    pub dir: Vector
+}
+
+impl Ray {
+    /// Creates a ray using an origin and a (non-zero) direction, which gets normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Ray;
+    /// # use anima_engine::math::Vector;
+    /// let r = Ray::new(Vector::one(), Vector::new(0.0, 3.0, 0.0));
+    ///
+    /// assert_eq!(r, Ray { origin: Vector::one(), dir: Vector::up() });
+    /// ```
+    pub fn new(origin: Vector, dir: Vector) -> Ray {
+        Ray {
+            origin: origin,
+            dir: dir.norm()
+        }
+    }
+
+    /// Computes the point found at distance `t` along the ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Ray;
+    /// # use anima_engine::math::Vector;
+    /// let r = Ray::new(Vector::one(), Vector::up());
+    ///
+    /// assert_eq!(r.at(0.0), Vector::one());
+    /// assert_eq!(r.at(2.0), Vector::new(1.0, 3.0, 1.0));
+    /// ```
+    pub fn at(&self, t: f32) -> Vector {
+        self.origin + self.dir * t
+    }
+}
+
+use mrusty::*;
+
+mrusty_class!(Ray, {
+    def!("initialize", |mruby, origin: Value, dir: Value| {
+        let origin = try_obj!(mruby, origin, Vector);
+        let dir = try_obj!(mruby, dir, Vector);
+
+        Ray::new(origin, dir)
+    });
+
+    def!("origin", |mruby, slf: Ray| {
+        mruby.obj(slf.origin)
+    });
+
+    def!("dir", |mruby, slf: Ray| {
+        mruby.obj(slf.dir)
+    });
+
+    def!("at", |mruby, slf: Ray, t: f64| {
+        mruby.obj(slf.at(t as f32))
+    });
+
+    def!("==", |mruby, slf: Ray, other: Value| {
+        let other = try_obj!(other, Ray, else mruby.bool(false));
+
+        let result = slf.origin == other.origin &&
+                     slf.dir == other.dir;
+
+        mruby.bool(result)
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::Ray;
+    use super::super::Vector;
+
+    describe!(Ray, (Vector), "
+      context 'when going up' do
+        subject { Ray.new Vector.one, Vector.up * 2.0 }
+
+        it 'returns origin on #origin' do
+          expect(subject.origin).to eql Vector.one
+        end
+
+        it 'normalizes direction on #dir' do
+          expect(subject.dir).to eql Vector.up
+        end
+
+        it 'computes point along the ray on #at' do
+          expect(subject.at 2.0).to eql Vector.new(1.0, 3.0, 1.0)
+        end
+      end
+    ");
+}
@@ -0,0 +1,304 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::f32::consts;
+
+/// Computes the clamped Hermite interpolation (*3t² - 2t³*) of `x` between `edge0` and `edge1`.
+/// When `edge0 == edge1` the result is a step: `0.0` below the edge and `1.0` from it onwards.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math;
+/// assert_eq!(math::smoothstep(1.0, 3.0, 0.0), 0.0);
+/// assert_eq!(math::smoothstep(1.0, 3.0, 1.0), 0.0);
+/// assert_eq!(math::smoothstep(1.0, 3.0, 2.0), 0.5);
+/// assert_eq!(math::smoothstep(1.0, 3.0, 3.0), 1.0);
+/// assert_eq!(math::smoothstep(1.0, 3.0, 4.0), 1.0);
+/// assert_eq!(math::smoothstep(1.0, 1.0, 1.0), 1.0);
+/// ```
+pub fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = step_ratio(edge0, edge1, x);
+
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Computes the clamped 6th-order interpolation (*6t⁵ - 15t⁴ + 10t³*) of `x` between `edge0`
+/// and `edge1`, which also has zero second derivatives at the edges. When `edge0 == edge1` the
+/// result is a step: `0.0` below the edge and `1.0` from it onwards.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math;
+/// assert_eq!(math::smootherstep(1.0, 3.0, 0.0), 0.0);
+/// assert_eq!(math::smootherstep(1.0, 3.0, 1.0), 0.0);
+/// assert_eq!(math::smootherstep(1.0, 3.0, 2.0), 0.5);
+/// assert_eq!(math::smootherstep(1.0, 3.0, 3.0), 1.0);
+/// assert_eq!(math::smootherstep(1.0, 3.0, 4.0), 1.0);
+/// assert_eq!(math::smootherstep(1.0, 1.0, 0.0), 0.0);
+/// ```
+pub fn smootherstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = step_ratio(edge0, edge1, x);
+
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+/// Wraps an angle in radians into `(-π, π]`.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math;
+/// # use std::f32::consts;
+/// assert_eq!(math::wrap_angle(1.0), 1.0);
+/// assert_eq!(math::wrap_angle(consts::PI), consts::PI);
+/// assert_eq!(math::wrap_angle(-consts::PI), consts::PI);
+/// assert!((math::wrap_angle(3.0 * consts::PI / 2.0) + consts::PI / 2.0).abs() < 0.00001);
+/// ```
+pub fn wrap_angle(a: f32) -> f32 {
+    let a = a % (2.0 * consts::PI);
+
+    if a <= -consts::PI {
+        a + 2.0 * consts::PI
+    } else if a > consts::PI {
+        a - 2.0 * consts::PI
+    } else {
+        a
+    }
+}
+
+/// Linearly interpolates between two angles in radians along the shortest path, which may wrap
+/// through `±π`. The result is wrapped into `(-π, π]`.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math;
+/// let a = math::lerp_angle(3.0, -3.0, 0.5);
+///
+/// // Naive interpolation would cross 0.0.
+/// assert!(a.abs() > 3.1);
+/// assert!((math::lerp_angle(3.0, -3.0, 0.25) - 3.0708).abs() < 0.0001);
+/// ```
+pub fn lerp_angle(from: f32, to: f32, ratio: f32) -> f32 {
+    wrap_angle(from + wrap_angle(to - from) * ratio)
+}
+
+/// Computes the ratio at which `value` lies between `a` and `b`, i.e. the inverse of linear
+/// interpolation. Ratios are not clamped. When `a == b` the result is `0.0`.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math;
+/// assert_eq!(math::inverse_lerp(2.0, 4.0, 3.0), 0.5);
+/// assert_eq!(math::inverse_lerp(2.0, 4.0, 5.0), 1.5);
+/// assert_eq!(math::inverse_lerp(4.0, 2.0, 3.5), 0.25);
+/// assert_eq!(math::inverse_lerp(2.0, 2.0, 3.0), 0.0);
+/// ```
+pub fn inverse_lerp(a: f32, b: f32, value: f32) -> f32 {
+    if a == b {
+        return 0.0;
+    }
+
+    (value - a) / (b - a)
+}
+
+/// Remaps `value` from the range `[in_min, in_max]` to the range `[out_min, out_max]` linearly.
+/// Values outside of the input range extrapolate past the output range; use `remap_clamped`
+/// when that is not wanted. When `in_min == in_max` the result is `out_min`.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math;
+/// assert_eq!(math::remap(5.0, 0.0, 10.0, 100.0, 200.0), 150.0);
+/// assert_eq!(math::remap(20.0, 0.0, 10.0, 100.0, 200.0), 300.0);
+/// assert_eq!(math::remap(5.0, 10.0, 0.0, 0.0, 1.0), 0.5);
+/// assert_eq!(math::remap(5.0, 1.0, 1.0, 100.0, 200.0), 100.0);
+/// ```
+pub fn remap(value: f32, in_min: f32, in_max: f32, out_min: f32, out_max: f32) -> f32 {
+    if in_min == in_max {
+        return out_min;
+    }
+
+    out_min + (value - in_min) / (in_max - in_min) * (out_max - out_min)
+}
+
+/// Remaps `value` like `remap`, but clamps the result to the output range.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math;
+/// assert_eq!(math::remap_clamped(5.0, 0.0, 10.0, 100.0, 200.0), 150.0);
+/// assert_eq!(math::remap_clamped(20.0, 0.0, 10.0, 100.0, 200.0), 200.0);
+/// assert_eq!(math::remap_clamped(-5.0, 0.0, 10.0, 200.0, 100.0), 200.0);
+/// assert_eq!(math::remap_clamped(5.0, 1.0, 1.0, 100.0, 200.0), 100.0);
+/// ```
+pub fn remap_clamped(value: f32, in_min: f32, in_max: f32, out_min: f32, out_max: f32) -> f32 {
+    if in_min == in_max {
+        return out_min;
+    }
+
+    let ratio = ((value - in_min) / (in_max - in_min)).max(0.0).min(1.0);
+
+    out_min + ratio * (out_max - out_min)
+}
+
+fn step_ratio(edge0: f32, edge1: f32, x: f32) -> f32 {
+    if edge0 == edge1 {
+        if x < edge0 { 0.0 } else { 1.0 }
+    } else {
+        ((x - edge0) / (edge1 - edge0)).max(0.0).min(1.0)
+    }
+}
+
+/// A `struct` exposing the scalar helpers in this `mod` to mruby as `Scalar` class methods.
+pub struct Scalar;
+
+use mrusty::*;
+
+use math::{self, Behavior};
+use super::interpolator::to_behavior;
+
+mrusty_class!(Scalar, {
+    def_self!("smoothstep", |mruby, _slf: Value, edge0: f64, edge1: f64, x: f64| {
+        mruby.float(smoothstep(edge0 as f32, edge1 as f32, x as f32) as f64)
+    });
+
+    def_self!("smootherstep", |mruby, _slf: Value, edge0: f64, edge1: f64, x: f64| {
+        mruby.float(smootherstep(edge0 as f32, edge1 as f32, x as f32) as f64)
+    });
+
+    def_self!("wrap_angle", |mruby, _slf: Value, a: f64| {
+        mruby.float(wrap_angle(a as f32) as f64)
+    });
+
+    def_self!("lerp_angle", |mruby, _slf: Value, from: f64, to: f64, ratio: f64| {
+        mruby.float(lerp_angle(from as f32, to as f32, ratio as f32) as f64)
+    });
+
+    def_self!("inverse_lerp", |mruby, _slf: Value, a: f64, b: f64, value: f64| {
+        mruby.float(inverse_lerp(a as f32, b as f32, value as f32) as f64)
+    });
+
+    def_self!("remap", |mruby, _slf: Value, value: f64, in_min: f64, in_max: f64, out_min: f64,
+                        out_max: f64| {
+        mruby.float(remap(value as f32, in_min as f32, in_max as f32, out_min as f32,
+                          out_max as f32) as f64)
+    });
+
+    def_self!("remap_clamped", |mruby, _slf: Value, value: f64, in_min: f64, in_max: f64,
+                                out_min: f64, out_max: f64| {
+        mruby.float(remap_clamped(value as f32, in_min as f32, in_max as f32, out_min as f32,
+                                  out_max as f32) as f64)
+    });
+
+    def_self!("ease", |mruby, _slf: Value, behavior: Value, t: f64| {
+        match to_behavior(mruby.clone(), &behavior) {
+            Ok(behavior) => mruby.float(math::ease(behavior, t as f32) as f64),
+            Err(error)   => error
+        }
+    });
+
+    def_self!("behaviors", |mruby, _slf: Value| {
+        let symbols = Behavior::all().iter().map(|b| mruby.symbol(&b.to_string())).collect();
+
+        mruby.array(symbols)
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::Scalar;
+    use super::super::Interpolator;
+
+    describe!(Scalar, (Interpolator), "
+      context 'when stepping' do
+        it 'interpolates at the midpoint on .smoothstep' do
+          expect(Scalar.smoothstep 1.0, 3.0, 2.0).to eql 0.5
+        end
+
+        it 'clamps outside the edges on .smoothstep' do
+          expect(Scalar.smoothstep 1.0, 3.0, 0.0).to eql 0.0
+          expect(Scalar.smoothstep 1.0, 3.0, 4.0).to eql 1.0
+        end
+
+        it 'interpolates at the midpoint on .smootherstep' do
+          expect(Scalar.smootherstep 1.0, 3.0, 2.0).to eql 0.5
+        end
+
+        it 'clamps outside the edges on .smootherstep' do
+          expect(Scalar.smootherstep 1.0, 3.0, 0.0).to eql 0.0
+          expect(Scalar.smootherstep 1.0, 3.0, 4.0).to eql 1.0
+        end
+      end
+
+      context 'when remapping' do
+        it 'recovers the ratio on .inverse_lerp' do
+          expect(Scalar.inverse_lerp 2.0, 4.0, 3.0).to eql 0.5
+        end
+
+        it 'converts between ranges on .remap' do
+          expect(Scalar.remap 5.0, 0.0, 10.0, 100.0, 200.0).to eql 150.0
+          expect(Scalar.remap 20.0, 0.0, 10.0, 100.0, 200.0).to eql 300.0
+        end
+
+        it 'clamps to the output range on .remap_clamped' do
+          expect(Scalar.remap_clamped 20.0, 0.0, 10.0, 100.0, 200.0).to eql 200.0
+        end
+      end
+
+      context 'when wrapping angles' do
+        it 'wraps into range on .wrap_angle' do
+          expect(Scalar.wrap_angle 7.0).to be_within(0.00001).of 0.716815
+        end
+
+        it 'takes the shortest path on .lerp_angle' do
+          expect(Scalar.lerp_angle 3.0, -3.0, 0.25).to be_within(0.0001).of 3.0708
+        end
+      end
+
+      context 'when easing' do
+        it 'eases linearly on .ease' do
+          expect(Scalar.ease :linear, 0.25).to eql 0.25
+        end
+
+        it 'eases acceleratingly on .ease' do
+          expect(Scalar.ease :acc, 0.25).to eql 0.0625
+        end
+
+        it 'eases deceleratingly on .ease' do
+          expect(Scalar.ease :dec, 0.25).to eql 0.4375
+        end
+
+        it 'eases accelerate-deceleratingly on .ease' do
+          expect(Scalar.ease :accdec, 0.25).to be_within(0.000001).of 0.146446
+        end
+
+        it 'matches Interpolator#ratio for all behaviors on .ease' do
+          Scalar.behaviors.each do |behavior|
+            interpolator = Interpolator.new 0.0, 1.0, behavior
+
+            expect(Scalar.ease behavior, 0.25).to eql interpolator.ratio(0.25)
+          end
+        end
+
+        it 'lists all behaviors on .behaviors' do
+          expect(Scalar.behaviors).to eql [:linear, :acc, :dec, :accdec]
+        end
+
+        it 'raises ArgumentError for unknown behaviors on .ease' do
+          expect { Scalar.ease :bounce, 0.25 }.to raise_error ArgumentError
+        end
+      end
+    ");
+}
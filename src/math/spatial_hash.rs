@@ -0,0 +1,199 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::collections::HashMap;
+
+use math::{Aabb, Vector};
+
+/// A uniform spatial hash `struct` useful for collision broadphase.
+///
+/// Space is split into cubic cells of `cell_size`; every inserted id is stored in all the cells
+/// its `Aabb` overlaps. `query` returns the ids sharing at least one cell with the queried box.
+/// These are only *candidates*: boxes in the same cell do not necessarily overlap, so callers
+/// still need to run precise tests (e.g. `Aabb::intersects`) on the result.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Aabb;
+/// # use anima_engine::math::SpatialHash;
+/// # use anima_engine::math::Vector;
+/// let mut h = SpatialHash::new(1.0);
+///
+/// h.insert(1, Aabb::new(Vector::zero(), Vector::new_unf(0.5)));
+/// h.insert(2, Aabb::new(Vector::new_unf(10.0), Vector::new_unf(10.5)));
+///
+/// assert_eq!(h.query(Aabb::new(Vector::new_unf(0.25), Vector::new_unf(0.75))), vec![1]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpatialHash {
+    /// `f32` edge length of the cubic cells
+    pub cell_size: f32,
+    cells: HashMap<(i32, i32, i32), Vec<u32>>
+}
+
+impl SpatialHash {
+    /// Creates an empty spatial hash with cubic cells of `cell_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is not a positive, finite number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Aabb;
+    /// # use anima_engine::math::SpatialHash;
+    /// # use anima_engine::math::Vector;
+    /// let h = SpatialHash::new(2.0);
+    ///
+    /// assert!(h.query(Aabb::new(Vector::zero(), Vector::one())).is_empty());
+    /// ```
+    pub fn new(cell_size: f32) -> SpatialHash {
+        assert!(cell_size > 0.0 && cell_size.is_finite(),
+                "SpatialHash cell size {} should be positive and finite.", cell_size);
+
+        SpatialHash {
+            cell_size: cell_size,
+            cells: HashMap::new()
+        }
+    }
+
+    /// Inserts an `id` in all the cells overlapped by `aabb`.
+    pub fn insert(&mut self, id: u32, aabb: Aabb) {
+        for cell in self.cells_of(aabb) {
+            self.cells.entry(cell).or_default().push(id);
+        }
+    }
+
+    /// Computes the sorted, deduplicated ids sharing at least one cell with `aabb`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Aabb;
+    /// # use anima_engine::math::SpatialHash;
+    /// # use anima_engine::math::Vector;
+    /// let mut h = SpatialHash::new(1.0);
+    ///
+    /// h.insert(3, Aabb::new(Vector::zero(), Vector::new_unf(2.5)));
+    /// h.insert(1, Aabb::new(Vector::new_unf(1.5), Vector::new_unf(1.75)));
+    ///
+    /// assert_eq!(h.query(Aabb::new(Vector::one(), Vector::new_unf(1.5))), vec![1, 3]);
+    /// ```
+    pub fn query(&self, aabb: Aabb) -> Vec<u32> {
+        let mut ids: Vec<u32> = self.cells_of(aabb).filter_map(|cell| {
+            self.cells.get(&cell)
+        }).flat_map(|ids| ids.iter().cloned()).collect();
+
+        ids.sort();
+        ids.dedup();
+
+        ids
+    }
+
+    /// Removes all ids.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    fn cell(&self, point: Vector) -> (i32, i32, i32) {
+        ((point.x / self.cell_size).floor() as i32,
+         (point.y / self.cell_size).floor() as i32,
+         (point.z / self.cell_size).floor() as i32)
+    }
+
+    // Cell coordinates saturate at the bounds of i32, so the ranges are inclusive instead of
+    // ending at x1 + 1. Cells are generated lazily since large boxes can span many of them.
+    fn cells_of(&self, aabb: Aabb) -> impl Iterator<Item = (i32, i32, i32)> {
+        let (x0, y0, z0) = self.cell(aabb.min);
+        let (x1, y1, z1) = self.cell(aabb.max);
+
+        (x0..=x1).flat_map(move |x| {
+            (y0..=y1).flat_map(move |y| (z0..=z1).map(move |z| (x, y, z)))
+        })
+    }
+}
+
+use mrusty::*;
+
+mrusty_class!(SpatialHash, {
+    def!("initialize", |mruby, cell_size: f64, entries: Vec| {
+        if !(cell_size > 0.0 && cell_size.is_finite()) {
+            return mruby.raise("ArgumentError", "cell size should be positive and finite");
+        }
+
+        let mut hash = SpatialHash::new(cell_size as f32);
+
+        for entry in entries {
+            let (id, aabb) = match entry.to_vec() {
+                Ok(ref pair) if pair.len() == 2 &&
+                                pair[0].class().to_str() == "Fixnum" &&
+                                pair[1].class().to_str() == "Aabb" => {
+                    (pair[0].to_i32().unwrap(), *pair[1].to_obj::<Aabb>().unwrap())
+                }
+                _ => {
+                    return mruby.raise("ArgumentError",
+                                       "Array should contain [Fixnum, Aabb] pairs")
+                }
+            };
+
+            hash.insert(id as u32, aabb);
+        }
+
+        hash
+    });
+
+    def!("cell_size", |mruby, slf: SpatialHash| {
+        mruby.float(slf.cell_size as f64)
+    });
+
+    def!("query", |mruby, slf: SpatialHash, aabb: Value| {
+        let aabb = try_obj!(mruby, aabb, Aabb);
+
+        let ids = slf.query(aabb).into_iter().map(|id| {
+            mruby.fixnum(id as i32)
+        }).collect();
+
+        mruby.array(ids)
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::SpatialHash;
+    use super::super::Aabb;
+    use super::super::Vector;
+
+    describe!(SpatialHash, (Aabb, Vector), "
+      context 'when filled' do
+        subject do
+          SpatialHash.new 1.0, [
+            [1, Aabb.new(Vector.zero, Vector.uniform(0.5))],
+            [2, Aabb.new(Vector.uniform(5.0), Vector.uniform(5.5))]
+          ]
+        end
+
+        it 'returns cell size on #cell_size' do
+          expect(subject.cell_size).to eql 1.0
+        end
+
+        it 'returns nearby ids on #query' do
+          expect(subject.query Aabb.new(Vector.uniform(0.25), Vector.uniform(0.75))).to eql [1]
+        end
+      end
+
+      context 'when passed a bad cell size' do
+        it 'raises ArgumentError on .new' do
+          expect { SpatialHash.new 0.0, [] }.to raise_error ArgumentError
+          expect { SpatialHash.new -1.0, [] }.to raise_error ArgumentError
+        end
+      end
+    ");
+}
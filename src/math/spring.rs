@@ -0,0 +1,215 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use math::Vector;
+
+/// A spring-damper `struct` useful for velocity-dependent motion like camera follow.
+///
+/// Every `step` integrates one time step with semi-implicit Euler. The integration is stable
+/// only while `stiffness * dt² + 2 * damping * dt < 4` (and `damping * dt < 2`); for large `dt`
+/// split the step into several smaller ones.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Spring;
+/// let s = Spring::new_crit(100.0);
+/// let mut position = 0.0;
+/// let mut velocity = 0.0;
+///
+/// for _ in 0..120 {
+///     position = s.step(position, 1.0, &mut velocity, 1.0 / 60.0);
+/// }
+///
+/// assert!((position - 1.0).abs() < 0.001);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spring {
+    /// `f32` force per unit of distance from the target
+    pub stiffness: f32,
+    /// `f32` force per unit of velocity opposing the motion
+    pub damping: f32
+}
+
+impl Spring {
+    /// Creates a spring using its stiffness and damping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Spring;
+    /// let s = Spring::new(10.0, 1.0);
+    ///
+    /// assert_eq!(s, Spring { stiffness: 10.0, damping: 1.0 });
+    /// ```
+    pub fn new(stiffness: f32, damping: f32) -> Spring {
+        Spring {
+            stiffness: stiffness,
+            damping: damping
+        }
+    }
+
+    /// Creates a critically damped spring, which reaches the target as fast as possible without
+    /// overshooting. (`damping = 2 √stiffness`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Spring;
+    /// let s = Spring::new_crit(16.0);
+    ///
+    /// assert_eq!(s, Spring { stiffness: 16.0, damping: 8.0 });
+    /// ```
+    pub fn new_crit(stiffness: f32) -> Spring {
+        Spring {
+            stiffness: stiffness,
+            damping: 2.0 * stiffness.sqrt()
+        }
+    }
+
+    /// Advances `current` towards `target` by `dt` seconds, updating `velocity` in place and
+    /// returning the new position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Spring;
+    /// let s = Spring::new(1.0, 0.0);
+    /// let mut velocity = 0.0;
+    ///
+    /// assert_eq!(s.step(0.0, 1.0, &mut velocity, 0.5), 0.25);
+    /// assert_eq!(velocity, 0.5);
+    /// ```
+    pub fn step(&self, current: f32, target: f32, velocity: &mut f32, dt: f32) -> f32 {
+        let acceleration = self.stiffness * (target - current) - self.damping * *velocity;
+
+        *velocity = *velocity + acceleration * dt;
+
+        current + *velocity * dt
+    }
+
+    /// Advances a `Vector` towards `target` by `dt` seconds, applying `step` per axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Spring;
+    /// # use anima_engine::math::Vector;
+    /// let s = Spring::new(1.0, 0.0);
+    /// let mut velocity = Vector::zero();
+    ///
+    /// let v = s.step_vec(Vector::zero(), Vector::one(), &mut velocity, 0.5);
+    ///
+    /// assert_eq!(v, Vector::new_unf(0.25));
+    /// assert_eq!(velocity, Vector::new_unf(0.5));
+    /// ```
+    pub fn step_vec(&self, current: Vector, target: Vector, velocity: &mut Vector,
+                    dt: f32) -> Vector {
+        Vector {
+            x: self.step(current.x, target.x, &mut velocity.x, dt),
+            y: self.step(current.y, target.y, &mut velocity.y, dt),
+            z: self.step(current.z, target.z, &mut velocity.z, dt)
+        }
+    }
+}
+
+use mrusty::*;
+
+mrusty_class!(Spring, {
+    def!("initialize", |stiffness: f64, damping: f64| {
+        Spring::new(stiffness as f32, damping as f32)
+    });
+
+    def_self!("critical", |mruby, _slf: Value, stiffness: f64| {
+        mruby.obj(Spring::new_crit(stiffness as f32))
+    });
+
+    def!("stiffness", |mruby, slf: Spring| {
+        mruby.float(slf.stiffness as f64)
+    });
+
+    def!("damping", |mruby, slf: Spring| {
+        mruby.float(slf.damping as f64)
+    });
+
+    def!("==", |mruby, slf: Spring, other: Value| {
+        let other = try_obj!(other, Spring, else mruby.bool(false));
+
+        let result = slf.stiffness == other.stiffness &&
+                     slf.damping == other.damping;
+
+        mruby.bool(result)
+    });
+
+    def!("to_s", |mruby, slf: Spring| {
+        let string = format!("<Spring: @stiffness={} @damping={}>", slf.stiffness, slf.damping);
+
+        mruby.string(&string)
+    });
+
+    def!("step", |mruby, slf: Spring, current: Value, target: Value, velocity: Value, dt: f64| {
+        match (current.class().to_str(), target.class().to_str(), velocity.class().to_str()) {
+            ("Float", "Float", "Float") => {
+                let mut v = velocity.to_f64().unwrap() as f32;
+                let position = slf.step(current.to_f64().unwrap() as f32,
+                                        target.to_f64().unwrap() as f32, &mut v, dt as f32);
+
+                mruby.array(vec![mruby.float(position as f64), mruby.float(v as f64)])
+            }
+            ("Vector", "Vector", "Vector") => {
+                let mut v = (*velocity.to_obj::<Vector>().unwrap()).clone();
+                let position = slf.step_vec((*current.to_obj::<Vector>().unwrap()).clone(),
+                                            (*target.to_obj::<Vector>().unwrap()).clone(),
+                                            &mut v, dt as f32);
+
+                mruby.array(vec![mruby.obj(position), mruby.obj(v)])
+            }
+            _ => mruby.raise("TypeError", "expecting all Float or all Vector")
+        }
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::Spring;
+    use super::super::Vector;
+
+    describe!(Spring, (Vector), "
+      context 'when critically damped' do
+        subject { Spring.critical 16.0 }
+
+        it 'returns stiffness on #stiffness' do
+          expect(subject.stiffness).to eql 16.0
+        end
+
+        it 'returns damping on #damping' do
+          expect(subject.damping).to eql 8.0
+        end
+
+        it 'converts to String on #to_s' do
+          expect(subject.to_s).to eql '<Spring: @stiffness=16 @damping=8>'
+        end
+      end
+
+      context 'when undamped' do
+        subject { Spring.new 1.0, 0.0 }
+
+        it 'steps Floats on #step' do
+          expect(subject.step 0.0, 1.0, 0.0, 0.5).to eql [0.25, 0.5]
+        end
+
+        it 'steps Vectors on #step' do
+          position, velocity = subject.step Vector.zero, Vector.one, Vector.zero, 0.5
+
+          expect(position).to eql Vector.uniform 0.25
+          expect(velocity).to eql Vector.uniform 0.5
+        end
+      end
+    ");
+}
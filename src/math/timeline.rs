@@ -0,0 +1,192 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use math::Interpolator;
+
+/// A `struct` useful for sequencing `Interpolator`s, e.g. "move, then wait, then rotate."
+///
+/// Every entry is an `Interpolator` offset by some starting time on the timeline. Entries are
+/// keyed by the index returned by `add`. An entry is active on the closed interval
+/// `[offset + start, offset + start + duration]`, so that it always reaches its final ratio of
+/// `1.0`. At the boundary between sequential entries, both are active: the finishing one at `1.0`
+/// and the next one at `0.0`.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Behavior;
+/// # use anima_engine::math::Interpolator;
+/// # use anima_engine::math::Timeline;
+/// let mut t = Timeline::new();
+///
+/// let walk = t.add(Interpolator::new(0.0, 2.0, Behavior::Linear), 0.0);
+/// let turn = t.add(Interpolator::new(0.0, 1.0, Behavior::Linear), 3.0);
+///
+/// assert_eq!(t.sample(1.0), vec![(walk, 0.5)]);
+/// assert_eq!(t.sample(2.5), vec![]);
+/// assert_eq!(t.sample(3.5), vec![(turn, 0.5)]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Timeline {
+    /// `Vec<(f32, Interpolator)>` of entries, each an `Interpolator` with its offset on the
+    /// timeline; the key of an entry is its index
+    pub entries: Vec<(f32, Interpolator)>
+}
+
+impl Timeline {
+    /// Creates an empty timeline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Timeline;
+    /// let t = Timeline::new();
+    ///
+    /// assert_eq!(t, Timeline { entries: vec![] });
+    /// ```
+    pub fn new() -> Timeline {
+        Timeline {
+            entries: vec![]
+        }
+    }
+
+    /// Adds an `Interpolator` offset by `at` on the timeline and returns its key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Behavior;
+    /// # use anima_engine::math::Interpolator;
+    /// # use anima_engine::math::Timeline;
+    /// let mut t = Timeline::new();
+    /// let i = Interpolator::new(0.0, 1.0, Behavior::Linear);
+    ///
+    /// assert_eq!(t.add(i, 0.0), 0);
+    /// assert_eq!(t.add(i, 1.0), 1);
+    /// ```
+    pub fn add(&mut self, interpolator: Interpolator, at: f32) -> usize {
+        self.entries.push((at, interpolator));
+
+        self.entries.len() - 1
+    }
+
+    /// Computes the keys and ratios of all entries active at some given `time`, in the order in
+    /// which they were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Behavior;
+    /// # use anima_engine::math::Interpolator;
+    /// # use anima_engine::math::Timeline;
+    /// let mut t = Timeline::new();
+    ///
+    /// t.add(Interpolator::new(0.0, 2.0, Behavior::Linear), 0.0);
+    /// t.add(Interpolator::new(1.0, 2.0, Behavior::Linear), 0.0);
+    ///
+    /// assert_eq!(t.sample(1.5), vec![(0, 0.75), (1, 0.25)]);
+    /// ```
+    pub fn sample(&self, time: f32) -> Vec<(usize, f32)> {
+        self.entries.iter().enumerate().filter_map(|(key, &(at, ref interpolator))| {
+            let local = time - at;
+            let start = interpolator.start;
+            let end = start + interpolator.duration;
+
+            if local == end {
+                Some((key, 1.0))
+            } else if local >= start && local < end {
+                Some((key, interpolator.ratio(local)))
+            } else {
+                None
+            }
+        }).collect()
+    }
+}
+
+impl Default for Timeline {
+    fn default() -> Timeline {
+        Timeline::new()
+    }
+}
+
+use mrusty::*;
+
+mrusty_class!(Timeline, {
+    def!("initialize", |mruby, entries: Vec| {
+        let mut timeline = Timeline::new();
+
+        for entry in entries {
+            let pair = match entry.to_vec() {
+                Ok(ref pair) if pair.len() == 2 &&
+                                pair[0].class().to_str() == "Interpolator" &&
+                                pair[1].class().to_str() == "Float" => {
+                    (*pair[0].to_obj::<Interpolator>().unwrap(),
+                     pair[1].to_f64().unwrap())
+                }
+                _ => {
+                    return mruby.raise("ArgumentError",
+                                       "Array should contain [Interpolator, Float] pairs")
+                }
+            };
+
+            timeline.add(pair.0, pair.1 as f32);
+        }
+
+        timeline
+    });
+
+    def!("size", |mruby, slf: Timeline| {
+        mruby.fixnum(slf.entries.len() as i32)
+    });
+
+    def!("sample", |mruby, slf: Timeline, time: f64| {
+        let active = slf.sample(time as f32).into_iter().map(|(key, ratio)| {
+            mruby.array(vec![mruby.fixnum(key as i32), mruby.float(ratio as f64)])
+        }).collect();
+
+        mruby.array(active)
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::Timeline;
+    use super::super::Interpolator;
+
+    describe!(Timeline, (Interpolator), "
+      context 'when sequential' do
+        subject do
+          Timeline.new [
+            [Interpolator.new(0.0, 1.0, :linear), 0.0],
+            [Interpolator.new(0.0, 1.0, :linear), 1.0]
+          ]
+        end
+
+        it 'returns number of entries on #size' do
+          expect(subject.size).to eql 2
+        end
+
+        it 'samples first entry on #sample' do
+          expect(subject.sample 0.5).to eql [[0, 0.5]]
+        end
+
+        it 'samples second entry on #sample' do
+          expect(subject.sample 1.25).to eql [[1, 0.25]]
+        end
+
+        it 'samples last entry at its end on #sample' do
+          expect(subject.sample 2.0).to eql [[1, 1.0]]
+        end
+
+        it 'samples nothing after the end on #sample' do
+          expect(subject.sample 2.5).to eql []
+        end
+      end
+    ");
+}
@@ -0,0 +1,332 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use math::{Matrix, Quaternion, Vector};
+
+/// A `struct` composing a translation, a rotation and a scale, applied in reverse order.
+/// (scale first, translation last)
+///
+/// The rotation is applied the same way `Matrix::rot` applies it. Interpolating two transforms
+/// interpolates their positions and scales linearly and their rotations spherically.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Transform;
+/// # use anima_engine::math::Quaternion;
+/// # use anima_engine::math::Vector;
+/// let t = Transform::new(Vector::new(1.0, 0.0, 0.0), Quaternion::ident(), Vector::new_unf(2.0));
+///
+/// assert_eq!(t.transform_point(Vector::one()), Vector::new(3.0, 2.0, 2.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    /// `Vector` translation
+    pub position: Vector,
+    /// `Quaternion` rotation
+    pub rotation: Quaternion,
+    /// `Vector` scale
+    pub scale: Vector
+}
+
+impl Transform {
+    /// Creates a transform using a position, a rotation and a scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Transform;
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// let t = Transform::new(Vector::zero(), Quaternion::ident(), Vector::one());
+    ///
+    /// assert_eq!(t, Transform::ident());
+    /// ```
+    pub fn new(position: Vector, rotation: Quaternion, scale: Vector) -> Transform {
+        Transform {
+            position: position,
+            rotation: rotation,
+            scale: scale
+        }
+    }
+
+    /// Creates an identity transform. (no translation, no rotation, unit scale)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Transform;
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(Transform::ident().transform_point(v), v);
+    /// ```
+    pub fn ident() -> Transform {
+        Transform {
+            position: Vector::zero(),
+            rotation: Quaternion::ident(),
+            scale: Vector::one()
+        }
+    }
+
+    /// Computes the matrix equivalent to a transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Matrix;
+    /// # use anima_engine::math::Transform;
+    /// assert_eq!(Transform::ident().to_matrix(), Matrix::ident());
+    /// ```
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::from_trs(self.position, self.rotation, self.scale)
+    }
+
+    /// Transforms a point by scaling, rotating and translating it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Transform;
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// let t = Transform::new(Vector::up(), Quaternion::ident(), Vector::new_unf(3.0));
+    ///
+    /// assert_eq!(t.transform_point(Vector::one()), Vector::new(3.0, 4.0, 3.0));
+    /// ```
+    pub fn transform_point(&self, point: Vector) -> Vector {
+        self.to_matrix() * point
+    }
+
+    /// Transforms a direction by rotating it. Translation and scale are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Transform;
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// let q = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    /// let t = Transform::new(Vector::up(), q, Vector::new_unf(3.0));
+    ///
+    /// assert_eq!(t.transform_direction(Vector::left()), Vector::right());
+    /// ```
+    pub fn transform_direction(&self, direction: Vector) -> Vector {
+        Matrix::ident().rot(self.rotation) * direction
+    }
+
+    /// Computes the inverse of a transform. The result is exact only for uniform scales, since
+    /// the inverse of a non-uniformly scaled and rotated transform cannot be represented as a
+    /// `Transform`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Transform;
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// let t = Transform::new(Vector::one(), Quaternion::ident(), Vector::new_unf(2.0));
+    /// let v = Vector::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(t.inverse().transform_point(t.transform_point(v)), v);
+    /// ```
+    pub fn inverse(&self) -> Transform {
+        let rotation = self.rotation.inv();
+        let scale = Vector::new(self.scale.x.recip(), self.scale.y.recip(), self.scale.z.recip());
+
+        Transform {
+            position: Matrix::ident().rot(rotation) * -(self.position * scale),
+            rotation: rotation,
+            scale: scale
+        }
+    }
+
+    /// Combines a parent transform with a `child` transform expressed relative to it. The result
+    /// is exact only when the parent's scale is uniform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Transform;
+    /// # use anima_engine::math::Quaternion;
+    /// # use anima_engine::math::Vector;
+    /// let parent = Transform::new(Vector::up(), Quaternion::ident(), Vector::new_unf(2.0));
+    /// let child = Transform::new(Vector::up(), Quaternion::ident(), Vector::one());
+    ///
+    /// assert_eq!(parent.combine(&child).position, Vector::new(0.0, 3.0, 0.0));
+    /// ```
+    pub fn combine(&self, child: &Transform) -> Transform {
+        Transform {
+            position: self.transform_point(child.position),
+            rotation: child.rotation * self.rotation,
+            scale: self.scale * child.scale
+        }
+    }
+}
+
+use mrusty::*;
+
+use math::Interpolate;
+
+impl Interpolate for Transform {
+    fn interpolate(&self, other: Transform, ratio: f32) -> Transform {
+        let rotation = if self.rotation == other.rotation {
+            self.rotation
+        } else {
+            self.rotation.interpolate(other.rotation, ratio)
+        };
+
+        Transform {
+            position: self.position.interpolate(other.position, ratio),
+            rotation: rotation,
+            scale: self.scale.interpolate(other.scale, ratio)
+        }
+    }
+}
+
+mrusty_class!(Transform, {
+    def!("initialize", |mruby, position: Value, rotation: Value, scale: Value| {
+        let position = try_obj!(mruby, position, Vector);
+        let rotation = try_obj!(mruby, rotation, Quaternion);
+        let scale = try_obj!(mruby, scale, Vector);
+
+        Transform::new(position, rotation, scale)
+    });
+
+    def_self!("identity", |mruby, _slf: Value| {
+        mruby.obj(Transform::ident())
+    });
+
+    def!("position", |mruby, slf: Transform| {
+        mruby.obj(slf.position)
+    });
+
+    def!("rotation", |mruby, slf: Transform| {
+        mruby.obj(slf.rotation)
+    });
+
+    def!("scale", |mruby, slf: Transform| {
+        mruby.obj(slf.scale)
+    });
+
+    def!("==", |mruby, slf: Transform, other: Value| {
+        let other = try_obj!(other, Transform, else mruby.bool(false));
+
+        let result = slf.position == other.position &&
+                     slf.rotation == other.rotation &&
+                     slf.scale == other.scale;
+
+        mruby.bool(result)
+    });
+
+    def!("to_s", |mruby, slf: Transform| {
+        let p = slf.position;
+        let r = slf.rotation;
+        let s = slf.scale;
+
+        let string = format!("<Transform: @position=<Vector: @x={} @y={} @z={}> \
+                              @rotation=<Quaternion: @x={} @y={} @z={} @w={}> \
+                              @scale=<Vector: @x={} @y={} @z={}>>",
+                             p.x, p.y, p.z, r.x, r.y, r.z, r.w, s.x, s.y, s.z);
+
+        mruby.string(&string)
+    });
+
+    def!("to_matrix", |mruby, slf: Transform| {
+        mruby.obj(slf.to_matrix())
+    });
+
+    def!("transform_point", |mruby, slf: Transform, point: Value| {
+        let point = try_obj!(mruby, point, Vector);
+
+        mruby.obj(slf.transform_point(point))
+    });
+
+    def!("transform_direction", |mruby, slf: Transform, direction: Value| {
+        let direction = try_obj!(mruby, direction, Vector);
+
+        mruby.obj(slf.transform_direction(direction))
+    });
+
+    def!("inverse", |mruby, slf: Transform| {
+        mruby.obj(slf.inverse())
+    });
+
+    def!("combine", |mruby, slf: Transform, child: Value| {
+        let child = try_obj!(mruby, child, Transform);
+
+        mruby.obj(slf.combine(&child))
+    });
+
+    def!("interpolate", |mruby, slf: Transform, other: Value, ratio: f64| {
+        let other = try_obj!(mruby, other, Transform);
+
+        mruby.obj(slf.interpolate(other, ratio as f32))
+    });
+});
+
+#[cfg(test)]
+mod tests {
+    use mrusty::*;
+
+    use super::Transform;
+    use super::super::Matrix;
+    use super::super::Quaternion;
+    use super::super::Vector;
+
+    describe!(Transform, (Matrix, Quaternion, Vector), "
+      context 'when identity' do
+        subject { Transform.identity }
+
+        it 'returns position on #position' do
+          expect(subject.position).to eql Vector.zero
+        end
+
+        it 'returns rotation on #rotation' do
+          expect(subject.rotation).to eql Quaternion.identity
+        end
+
+        it 'returns scale on #scale' do
+          expect(subject.scale).to eql Vector.one
+        end
+
+        it 'converts to Matrix on #to_matrix' do
+          expect(subject.to_matrix).to eql Matrix.identity
+        end
+      end
+
+      context 'when translated and scaled' do
+        subject { Transform.new Vector.up, Quaternion.identity, Vector.uniform(2.0) }
+
+        it 'transforms points on #transform_point' do
+          expect(subject.transform_point Vector.one).to eql Vector.new 2.0, 3.0, 2.0
+        end
+
+        it 'transforms directions on #transform_direction' do
+          expect(subject.transform_direction Vector.one).to eql Vector.one
+        end
+
+        it 'inverts on #inverse' do
+          expect(subject.inverse.transform_point Vector.new(2.0, 3.0, 2.0)).to eql Vector.one
+        end
+
+        it 'combines with a child on #combine' do
+          child = Transform.new Vector.up, Quaternion.identity, Vector.one
+
+          expect(subject.combine(child).position).to eql Vector.new 0.0, 3.0, 0.0
+        end
+
+        it 'interpolates on #interpolate' do
+          other = Transform.new Vector.down, Quaternion.identity, Vector.uniform(4.0)
+          interpolated = subject.interpolate(other, 0.5)
+
+          expect(interpolated.position).to eql Vector.zero
+          expect(interpolated.scale).to eql Vector.uniform 3.0
+        end
+      end
+    ");
+}
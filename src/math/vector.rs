@@ -6,6 +6,7 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use math::Quaternion;
+use math::scalar;
 
 /// A simple vector `struct` tailored specifically for graphics.
 ///
@@ -63,6 +64,43 @@ impl Vector {
         Vector { x: array[0], y: array[1], z: array[2] }
     }
 
+    /// Creates a vector from homogeneous coordinates. When `w` is not `0.0` the array is a point
+    /// and *x*, *y*, *z* are divided by `w` (perspective divide); when `w` is `0.0` the array is
+    /// a direction and *x*, *y*, *z* are returned as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// assert_eq!(Vector::from_array4([2.0, 4.0, 6.0, 2.0]), Vector::new(1.0, 2.0, 3.0));
+    /// assert_eq!(Vector::from_array4([2.0, 4.0, 6.0, 0.0]), Vector::new(2.0, 4.0, 6.0));
+    /// ```
+    pub fn from_array4(array: [f32; 4]) -> Vector {
+        let w = array[3];
+
+        if w == 0.0 {
+            Vector { x: array[0], y: array[1], z: array[2] }
+        } else {
+            Vector { x: array[0] / w, y: array[1] / w, z: array[2] / w }
+        }
+    }
+
+    /// Converts a vector to homogeneous coordinates with an explicit `w`: `1.0` for points,
+    /// `0.0` for directions. Components are not multiplied by `w`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(v.to_array4(1.0), [1.0, 2.0, 3.0, 1.0]);
+    /// assert_eq!(v.to_array4(0.0), [1.0, 2.0, 3.0, 0.0]);
+    /// ```
+    pub fn to_array4(self, w: f32) -> [f32; 4] {
+        [self.x, self.y, self.z, w]
+    }
+
     /// Creates a uniform vector using 1 value.
     ///
     /// # Examples
@@ -173,6 +211,42 @@ impl Vector {
         Vector { x: 0.0, y: 1.0, z: 0.0 }
     }
 
+    /// Creates a copy of a vector with the *x* coordinate replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// assert_eq!(Vector::one().with_x(5.0), Vector::new(5.0, 1.0, 1.0));
+    /// ```
+    pub fn with_x(self, x: f32) -> Vector {
+        Vector { x: x, y: self.y, z: self.z }
+    }
+
+    /// Creates a copy of a vector with the *y* coordinate replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// assert_eq!(Vector::one().with_y(5.0), Vector::new(1.0, 5.0, 1.0));
+    /// ```
+    pub fn with_y(self, y: f32) -> Vector {
+        Vector { x: self.x, y: y, z: self.z }
+    }
+
+    /// Creates a copy of a vector with the *z* coordinate replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// assert_eq!(Vector::one().with_z(5.0), Vector::new(1.0, 1.0, 5.0));
+    /// ```
+    pub fn with_z(self, z: f32) -> Vector {
+        Vector { x: self.x, y: self.y, z: z }
+    }
+
     /// Computes the length of a vector.
     ///
     /// # Examples
@@ -208,6 +282,87 @@ impl Vector {
         }
     }
 
+    /// Computes the normalized version of a vector, or returns `fallback` when the vector is too
+    /// short (length below `f32::EPSILON`) or not finite to have a meaningful direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// assert_eq!(Vector::zero().normalize_or(Vector::up()), Vector::up());
+    /// assert_eq!(Vector::new(0.0, 0.0, 2.0).normalize_or(Vector::up()), Vector::forward());
+    /// ```
+    pub fn normalize_or(&self, fallback: Vector) -> Vector {
+        let length = self.len();
+
+        if length.is_finite() && length >= f32::EPSILON {
+            self.norm()
+        } else {
+            fallback
+        }
+    }
+
+    /// Scales a vector down to length `max` if it is longer, keeping its direction. Shorter
+    /// vectors, including the zero vector, are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::new(3.0, 0.0, 4.0);
+    /// let c = v.clamp_length(3.0);
+    ///
+    /// const EPSILON: f32 = 0.00001;
+    ///
+    /// assert!((c.len() - 3.0).abs() < EPSILON);
+    /// assert!(c.norm().dist(v.norm()) < EPSILON);
+    /// assert_eq!(Vector::zero().clamp_length(3.0), Vector::zero());
+    /// ```
+    pub fn clamp_length(self, max: f32) -> Vector {
+        self.clamp_length_range(0.0, max)
+    }
+
+    /// Scales a vector up to length `min` if it is shorter, keeping its direction. Longer
+    /// vectors are returned unchanged; the zero vector has no direction and stays zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// assert_eq!(Vector::new(0.0, 0.5, 0.0).clamp_length_min(2.0), Vector::new(0.0, 2.0, 0.0));
+    /// assert_eq!(Vector::zero().clamp_length_min(2.0), Vector::zero());
+    /// ```
+    pub fn clamp_length_min(self, min: f32) -> Vector {
+        self.clamp_length_range(min, f32::INFINITY)
+    }
+
+    /// Scales a vector so that its length lies between `min` and `max`, keeping its direction.
+    /// The zero vector stays zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::new(0.0, 0.0, 5.0);
+    ///
+    /// assert_eq!(v.clamp_length_range(1.0, 2.0), Vector::new(0.0, 0.0, 2.0));
+    /// assert_eq!(v.clamp_length_range(6.0, 8.0), Vector::new(0.0, 0.0, 6.0));
+    /// assert_eq!(v.clamp_length_range(1.0, 8.0), v);
+    /// ```
+    pub fn clamp_length_range(self, min: f32, max: f32) -> Vector {
+        let len = self.len();
+
+        if len == 0.0 {
+            self
+        } else if len > max {
+            self * (max / len)
+        } else if len < min {
+            self * (min / len)
+        } else {
+            self
+        }
+    }
+
     /// Computes the dot product between two vectors.
     ///
     /// # Examples
@@ -244,6 +399,83 @@ impl Vector {
         }
     }
 
+    /// Computes the 2D cross product (perpendicular dot product) of two vectors on the *xz*
+    /// plane, ignoring *y*, e.g. for the winding of top-down polygons. It equals
+    /// `self.cross(other).y`, so it is positive when `other` is to the left of `self` seen from
+    /// `up`, negative when it is to the right and zero when they are parallel.
+    ///
+    /// Note that with the crate's axes, turning left from *x* goes towards *-z*, which is the
+    /// opposite direction of increasing `angle_xz`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let heading = Vector::forward();
+    ///
+    /// assert_eq!(heading.cross_xz(Vector::new(1.0, 0.0, 1.0)), 1.0); // left turn
+    /// assert_eq!(heading.cross_xz(Vector::new(-1.0, 0.0, 1.0)), -1.0); // right turn
+    /// assert_eq!(heading.cross_xz(Vector::new(0.0, 5.0, 2.0)), 0.0);
+    /// ```
+    pub fn cross_xz(&self, other: Vector) -> f32 {
+        self.z * other.x - self.x * other.z
+    }
+
+    /// Computes the 2D cross product (perpendicular dot product) of two vectors on the *xy*
+    /// plane, ignoring *z*. It equals `self.cross(other).z`, so it is positive when `other` is
+    /// to the left of `self` seen from `forward`, i.e. counterclockwise in the sense of
+    /// `from_angle_xy`, and negative when it is to the right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let heading = Vector::left();
+    ///
+    /// assert_eq!(heading.cross_xy(Vector::new(1.0, 1.0, 0.0)), 1.0); // left turn
+    /// assert_eq!(heading.cross_xy(Vector::new(1.0, -1.0, 0.0)), -1.0); // right turn
+    /// ```
+    pub fn cross_xy(&self, other: Vector) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Computes an orthonormal basis from a (non-zero) direction, e.g. to build a coordinate
+    /// frame along a curve's tangent. The first vector is the normalized direction and the
+    /// basis is right-handed, i.e. `first.cross(second) == third`, like
+    /// `left.cross(up) == forward`.
+    ///
+    /// The second vector is perpendicular to both the direction and the axis along the
+    /// direction's smallest component, which keeps the cross products far from degenerate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let (x, y, z) = Vector::new(0.0, 0.0, 2.0).orthonormal_basis();
+    ///
+    /// assert_eq!(x, Vector::forward());
+    /// assert_eq!(y, Vector::up());
+    /// assert_eq!(z, -Vector::left());
+    /// assert_eq!(x.cross(y), z);
+    /// ```
+    pub fn orthonormal_basis(&self) -> (Vector, Vector, Vector) {
+        let first = self.norm();
+
+        let (x, y, z) = (first.x.abs(), first.y.abs(), first.z.abs());
+        let axis = if x <= y && x <= z {
+            Vector::new(1.0, 0.0, 0.0)
+        } else if y <= z {
+            Vector::new(0.0, 1.0, 0.0)
+        } else {
+            Vector::new(0.0, 0.0, 1.0)
+        };
+
+        let second = first.cross(axis).norm();
+        let third = first.cross(second);
+
+        (first, second, third)
+    }
+
     /// Rotates a vector according to the rotation represented by a quaternion.
     ///
     /// # Examples
@@ -281,6 +513,71 @@ impl Vector {
         (self - point).rot(quaternion) + point
     }
 
+    /// Rotates a vector around the *x* axis by `radians`. Rotations are right-handed
+    /// (counter-clockwise when looking from the positive end of the axis towards the origin),
+    /// like rotating by `Matrix::ident().rot(Quaternion::new_rot(axis, radians))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// # use std::f32::consts;
+    /// let v = Vector::up().rotate_x(consts::PI / 2.0);
+    ///
+    /// assert!(v.dist(Vector::forward()) < 0.00001);
+    /// ```
+    pub fn rotate_x(self, radians: f32) -> Vector {
+        let (sin, cos) = radians.sin_cos();
+
+        Vector {
+            x: self.x,
+            y: self.y * cos - self.z * sin,
+            z: self.y * sin + self.z * cos
+        }
+    }
+
+    /// Rotates a vector around the *y* axis by `radians`. (right-handed, like `rotate_x`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// # use std::f32::consts;
+    /// let v = Vector::forward().rotate_y(consts::PI / 2.0);
+    ///
+    /// assert!(v.dist(Vector::left()) < 0.00001);
+    /// ```
+    pub fn rotate_y(self, radians: f32) -> Vector {
+        let (sin, cos) = radians.sin_cos();
+
+        Vector {
+            x: self.x * cos + self.z * sin,
+            y: self.y,
+            z: self.z * cos - self.x * sin
+        }
+    }
+
+    /// Rotates a vector around the *z* axis by `radians`. (right-handed, like `rotate_x`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// # use std::f32::consts;
+    /// let v = Vector::left().rotate_z(consts::PI / 2.0);
+    ///
+    /// assert!(v.dist(Vector::up()) < 0.00001);
+    /// ```
+    pub fn rotate_z(self, radians: f32) -> Vector {
+        let (sin, cos) = radians.sin_cos();
+
+        Vector {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+            z: self.z
+        }
+    }
+
     /// Computes the angle in radians between two vectors.
     ///
     /// # Examples
@@ -311,13 +608,555 @@ impl Vector {
     pub fn dist(self, other: Vector) -> f32 {
         (self - other).len()
     }
+
+    /// Computes the Manhattan (L1) distance between two vectors, i.e. the sum of absolute
+    /// component differences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v1 = Vector::new(0.0, 0.0, 0.0);
+    /// let v2 = Vector::new(3.0, 4.0, 0.0);
+    ///
+    /// assert_eq!(v1.dist(v2), 5.0);
+    /// assert_eq!(v1.manhattan_dist(v2), 7.0);
+    /// assert_eq!(v1.chebyshev_dist(v2), 4.0);
+    /// ```
+    pub fn manhattan_dist(self, other: Vector) -> f32 {
+        let diff = self - other;
+
+        diff.x.abs() + diff.y.abs() + diff.z.abs()
+    }
+
+    /// Computes the Chebyshev (L∞) distance between two vectors, i.e. the maximum absolute
+    /// component difference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v1 = Vector::new(1.0, 1.0, 1.0);
+    /// let v2 = Vector::new(3.0, -2.0, 2.0);
+    ///
+    /// assert_eq!(v1.chebyshev_dist(v2), 3.0);
+    /// ```
+    pub fn chebyshev_dist(self, other: Vector) -> f32 {
+        let diff = self - other;
+
+        diff.x.abs().max(diff.y.abs()).max(diff.z.abs())
+    }
+
+    /// Computes the distance between a point and the segment from `a` to `b`. Points beyond an
+    /// end are measured to that end. If `a` and `b` coincide, the distance to that point is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let a = Vector::new(0.0, 0.0, 0.0);
+    /// let b = Vector::new(2.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(Vector::new(1.0, 3.0, 0.0).dist_to_segment(a, b), 3.0);
+    /// assert_eq!(Vector::new(5.0, 4.0, 0.0).dist_to_segment(a, b), 5.0);
+    /// ```
+    pub fn dist_to_segment(self, a: Vector, b: Vector) -> f32 {
+        match self.project_on_line(a, b) {
+            Some(t) => self.dist(a + (b - a) * t.max(0.0).min(1.0)),
+            None    => self.dist(a)
+        }
+    }
+
+    /// Computes the distance between a point and the infinite line passing through `a` and `b`.
+    /// If `a` and `b` coincide, the distance to that point is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let a = Vector::new(0.0, 0.0, 0.0);
+    /// let b = Vector::new(2.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(Vector::new(1.0, 3.0, 0.0).dist_to_line(a, b), 3.0);
+    /// assert_eq!(Vector::new(5.0, 4.0, 0.0).dist_to_line(a, b), 4.0);
+    /// ```
+    pub fn dist_to_line(self, a: Vector, b: Vector) -> f32 {
+        match self.project_on_line(a, b) {
+            Some(t) => self.dist(a + (b - a) * t),
+            None    => self.dist(a)
+        }
+    }
+
+    /// Computes the ratio of a point's projection onto the line passing through `a` and `b`,
+    /// i.e. the inverse of `a.interpolate(b, ratio)` for points on the line. Ratios are not
+    /// clamped. If `a` and `b` coincide, `0.0` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let a = Vector::new(0.0, 0.0, 0.0);
+    /// let b = Vector::new(2.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(Vector::new(1.0, 0.0, 0.0).inverse_lerp(a, b), 0.5);
+    /// assert_eq!(Vector::new(1.0, 5.0, 0.0).inverse_lerp(a, b), 0.5);
+    /// assert_eq!(Vector::new(4.0, 0.0, 0.0).inverse_lerp(a, b), 2.0);
+    /// assert_eq!(Vector::one().inverse_lerp(a, a), 0.0);
+    /// ```
+    pub fn inverse_lerp(self, a: Vector, b: Vector) -> f32 {
+        self.project_on_line(a, b).unwrap_or(0.0)
+    }
+
+    fn project_on_line(self, a: Vector, b: Vector) -> Option<f32> {
+        let ab = b - a;
+        let len2 = ab.dot(ab);
+
+        if len2 == 0.0 {
+            None
+        } else {
+            Some((self - a).dot(ab) / len2)
+        }
+    }
+
+    /// Projects a vector onto the plane passing through the origin with the (non-zero) `normal`,
+    /// removing its component along the normal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::new(1.0, 1.0, 1.0);
+    ///
+    /// assert_eq!(v.project_on_plane(Vector::up()), Vector::new(1.0, 0.0, 1.0));
+    /// ```
+    pub fn project_on_plane(self, normal: Vector) -> Vector {
+        self - normal * (self.dot(normal) / normal.dot(normal))
+    }
+
+    /// Reflects a vector about the plane passing through the origin with the (non-zero) `normal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::new(1.0, -1.0, 1.0);
+    ///
+    /// assert_eq!(v.reflect(Vector::up()), Vector::new(1.0, 1.0, 1.0));
+    /// ```
+    pub fn reflect(self, normal: Vector) -> Vector {
+        self - normal * (2.0 * self.dot(normal) / normal.dot(normal))
+    }
+
+    /// Computes the largest component of a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::new(-3.0, 2.0, -1.0);
+    ///
+    /// assert_eq!(v.max_component(), 2.0);
+    /// ```
+    pub fn max_component(&self) -> f32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    /// Computes the smallest component of a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::new(-3.0, 2.0, -1.0);
+    ///
+    /// assert_eq!(v.min_component(), -3.0);
+    /// ```
+    pub fn min_component(&self) -> f32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    /// Computes the sum of the components of a vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::new(-3.0, 2.0, -1.0);
+    ///
+    /// assert_eq!(v.sum(), -2.0);
+    /// ```
+    pub fn sum(&self) -> f32 {
+        self.x + self.y + self.z
+    }
+
+    /// Snaps a vector to the nearest point of a grid, rounding every component to the nearest
+    /// multiple of the corresponding `cell` component. Axes with a zero `cell` component are left
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::new(1.2, 3.7, -0.4);
+    ///
+    /// assert_eq!(v.snap_to_grid(Vector::one()), Vector::new(1.0, 4.0, 0.0));
+    /// assert_eq!(v.snap_to_grid(Vector::new(0.5, 0.0, 1.0)), Vector::new(1.0, 3.7, 0.0));
+    /// ```
+    pub fn snap_to_grid(self, cell: Vector) -> Vector {
+        fn snap(value: f32, cell: f32) -> f32 {
+            if cell == 0.0 {
+                value
+            } else {
+                (value / cell).round() * cell
+            }
+        }
+
+        Vector {
+            x: snap(self.x, cell.x),
+            y: snap(self.y, cell.y),
+            z: snap(self.z, cell.z)
+        }
+    }
+
+    /// Snaps a vector to the nearest point of a uniform grid with cells of `size`, like
+    /// `snap_to_grid`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::new(1.2, 3.7, -0.4);
+    ///
+    /// assert_eq!(v.snap(1.0), Vector::new(1.0, 4.0, 0.0));
+    /// assert_eq!(v.snap(0.5), Vector::new(1.0, 3.5, -0.5));
+    /// ```
+    pub fn snap(self, size: f32) -> Vector {
+        self.snap_to_grid(Vector::new_unf(size))
+    }
+
+    /// Checks whether all components of a vector are finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// assert!(Vector::one().is_finite());
+    /// assert!(!Vector::new(1.0, 1.0 / 0.0, 1.0).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Checks whether any component of a vector is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// assert!(!Vector::one().is_nan());
+    /// assert!(Vector::zero().norm().is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    /// Computes the spherical coordinates `(radius, theta, phi)` of a vector. `up` (*y*) is the
+    /// pole: *theta* is the polar angle from `up`, in `[0, π]`, and *phi* is the azimuth around
+    /// it, in `(-π, π]`, measured from `forward` (*z*) towards `left` (*x*). The zero vector
+    /// returns zeroed angles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// # use std::f32::consts;
+    /// assert_eq!(Vector::up().to_spherical(), (1.0, 0.0, 0.0));
+    /// assert_eq!(Vector::forward().to_spherical(), (1.0, consts::PI / 2.0, 0.0));
+    /// assert_eq!(Vector::left().to_spherical(), (1.0, consts::PI / 2.0, consts::PI / 2.0));
+    /// assert_eq!(Vector::zero().to_spherical(), (0.0, 0.0, 0.0));
+    /// assert_eq!(Vector::new(-0.0, 0.0, -1.0).to_spherical().2, consts::PI);
+    /// ```
+    pub fn to_spherical(&self) -> (f32, f32, f32) {
+        let radius = self.len();
+
+        if radius == 0.0 {
+            return (0.0, 0.0, 0.0);
+        }
+
+        let theta = (self.y / radius).max(-1.0).min(1.0).acos();
+        let phi = self.x.atan2(self.z);
+
+        // atan2 returns -π for a -0.0 x behind the pole, which is the same azimuth as π.
+        if phi == -f32::consts::PI {
+            (radius, theta, f32::consts::PI)
+        } else {
+            (radius, theta, phi)
+        }
+    }
+
+    /// Creates a vector from spherical coordinates, using the conventions of `to_spherical`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// # use std::f32::consts;
+    /// let v = Vector::from_spherical(2.0, consts::PI / 2.0, consts::PI / 2.0);
+    ///
+    /// assert!(v.dist(Vector::new(2.0, 0.0, 0.0)) < 0.00001);
+    /// ```
+    pub fn from_spherical(radius: f32, theta: f32, phi: f32) -> Vector {
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+
+        Vector {
+            x: radius * sin_theta * sin_phi,
+            y: radius * cos_theta,
+            z: radius * sin_theta * cos_phi
+        }
+    }
+
+    /// Computes the centroid (arithmetic mean) of some `points`. An empty slice returns
+    /// `Vector::zero()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let mut corners = vec![];
+    ///
+    /// for &x in &[0.0, 1.0] {
+    ///     for &y in &[0.0, 1.0] {
+    ///         for &z in &[0.0, 1.0] {
+    ///             corners.push(Vector::new(x, y, z));
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Vector::centroid(&corners), Vector::new_unf(0.5));
+    /// assert_eq!(Vector::centroid(&[]), Vector::zero());
+    /// ```
+    pub fn centroid(points: &[Vector]) -> Vector {
+        if points.is_empty() {
+            return Vector::zero();
+        }
+
+        let sum = points.iter().fold(Vector::zero(), |sum, &point| sum + point);
+
+        sum * (1.0 / points.len() as f32)
+    }
+
+    /// Computes the weighted sum of some points (*Σ wᵢ · pᵢ*), e.g. for blend spaces or skinning.
+    /// Weights are used as they are, so they should add up to `1.0` for the result to be a blend
+    /// of the points. Empty input results in `Vector::zero()`.
+    ///
+    /// `points` and `weights` must have the same length, which is only checked in debug builds;
+    /// extra elements of the longer slice are otherwise ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let points = [Vector::zero(), Vector::new(2.0, 0.0, 0.0), Vector::new(0.0, 4.0, 0.0)];
+    ///
+    /// let blended = Vector::weighted_sum(&points, &[0.5, 0.25, 0.25]);
+    ///
+    /// assert_eq!(blended, Vector::new(0.5, 1.0, 0.0));
+    /// assert_eq!(Vector::weighted_sum(&[], &[]), Vector::zero());
+    /// ```
+    pub fn weighted_sum(points: &[Vector], weights: &[f32]) -> Vector {
+        debug_assert_eq!(points.len(), weights.len());
+
+        points.iter().zip(weights.iter()).fold(Vector::zero(), |sum, (&point, &weight)| {
+            sum + point * weight
+        })
+    }
+
+    /// Creates a unit vector on the *xz* plane, useful for top-down movement. An angle of `0.0`
+    /// points to `left` (*x*) and angles increase towards `forward` (*z*).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// # use std::f32::consts;
+    /// let v = Vector::from_angle_xz(consts::PI / 2.0);
+    ///
+    /// assert_eq!(Vector::from_angle_xz(0.0), Vector::left());
+    /// assert!(v.dist(Vector::forward()) < 0.00001);
+    /// assert!((v.angle_xz() - consts::PI / 2.0).abs() < 0.00001);
+    /// ```
+    pub fn from_angle_xz(radians: f32) -> Vector {
+        let (sin, cos) = radians.sin_cos();
+
+        Vector { x: cos, y: 0.0, z: sin }
+    }
+
+    /// Creates a unit vector on the *xy* plane. An angle of `0.0` points to `left` (*x*) and
+    /// angles increase towards `up` (*y*).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// # use std::f32::consts;
+    /// let v = Vector::from_angle_xy(consts::PI / 2.0);
+    ///
+    /// assert_eq!(Vector::from_angle_xy(0.0), Vector::left());
+    /// assert!(v.dist(Vector::up()) < 0.00001);
+    /// ```
+    pub fn from_angle_xy(radians: f32) -> Vector {
+        let (sin, cos) = radians.sin_cos();
+
+        Vector { x: cos, y: sin, z: 0.0 }
+    }
+
+    /// Computes the heading of a vector on the *xz* plane, in `(-π, π]`, using the conventions of
+    /// `from_angle_xz`. *y* is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::from_angle_xz(-2.5);
+    ///
+    /// assert!((v.angle_xz() + 2.5).abs() < 0.00001);
+    /// assert!((v.with_y(3.0).angle_xz() + 2.5).abs() < 0.00001);
+    /// ```
+    pub fn angle_xz(&self) -> f32 {
+        self.z.atan2(self.x)
+    }
+
+    /// Creates a vector from barycentric `weights` relative to the triangle `a`, `b`, `c`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let a = Vector::new(0.0, 0.0, 0.0);
+    /// let b = Vector::new(2.0, 0.0, 0.0);
+    /// let c = Vector::new(0.0, 2.0, 0.0);
+    ///
+    /// let v = Vector::from_barycentric(a, b, c, (0.5, 0.25, 0.25));
+    ///
+    /// assert_eq!(v, Vector::new(0.5, 0.5, 0.0));
+    /// ```
+    pub fn from_barycentric(a: Vector, b: Vector, c: Vector, weights: (f32, f32, f32)) -> Vector {
+        let (u, v, w) = weights;
+
+        a * u + b * v + c * w
+    }
+
+    /// Computes the component-wise `math::smoothstep` of `x` between `edge0` and `edge1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::smoothstep(Vector::zero(), Vector::one(), Vector::new(-1.0, 0.5, 2.0));
+    ///
+    /// assert_eq!(v, Vector::new(0.0, 0.5, 1.0));
+    /// ```
+    pub fn smoothstep(edge0: Vector, edge1: Vector, x: Vector) -> Vector {
+        Vector {
+            x: scalar::smoothstep(edge0.x, edge1.x, x.x),
+            y: scalar::smoothstep(edge0.y, edge1.y, x.y),
+            z: scalar::smoothstep(edge0.z, edge1.z, x.z)
+        }
+    }
+
+    /// Computes the component-wise `math::smootherstep` of `x` between `edge0` and `edge1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Vector;
+    /// let v = Vector::smootherstep(Vector::zero(), Vector::one(), Vector::new(-1.0, 0.5, 2.0));
+    ///
+    /// assert_eq!(v, Vector::new(0.0, 0.5, 1.0));
+    /// ```
+    pub fn smootherstep(edge0: Vector, edge1: Vector, x: Vector) -> Vector {
+        Vector {
+            x: scalar::smootherstep(edge0.x, edge1.x, x.x),
+            y: scalar::smootherstep(edge0.y, edge1.y, x.y),
+            z: scalar::smootherstep(edge0.z, edge1.z, x.z)
+        }
+    }
+}
+
+/// Computes the barycentric weights of `p` relative to the triangle `a`, `b`, `c`. `p` is
+/// projected onto the triangle's plane. Degenerate (zero-area) triangles produce non-finite
+/// weights; use `try_barycentric` to detect them.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math;
+/// # use anima_engine::math::Vector;
+/// let a = Vector::new(0.0, 0.0, 0.0);
+/// let b = Vector::new(2.0, 0.0, 0.0);
+/// let c = Vector::new(0.0, 2.0, 0.0);
+///
+/// assert_eq!(math::barycentric(Vector::new(0.5, 0.5, 0.0), a, b, c), (0.5, 0.25, 0.25));
+/// ```
+pub fn barycentric(p: Vector, a: Vector, b: Vector, c: Vector) -> (f32, f32, f32) {
+    let (v, w, _) = barycentric_raw(p, a, b, c);
+
+    (1.0 - v - w, v, w)
+}
+
+/// Computes the barycentric weights of `p` relative to the triangle `a`, `b`, `c`, or `None` if
+/// the triangle is degenerate. (zero-area)
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math;
+/// # use anima_engine::math::Vector;
+/// let a = Vector::new(0.0, 0.0, 0.0);
+/// let b = Vector::new(1.0, 1.0, 1.0);
+/// let c = Vector::new(2.0, 2.0, 2.0);
+///
+/// assert_eq!(math::try_barycentric(Vector::one(), a, b, c), None);
+/// ```
+pub fn try_barycentric(p: Vector, a: Vector, b: Vector, c: Vector) -> Option<(f32, f32, f32)> {
+    let (v, w, degenerate) = barycentric_raw(p, a, b, c);
+
+    if degenerate {
+        None
+    } else {
+        Some((1.0 - v - w, v, w))
+    }
+}
+
+fn barycentric_raw(p: Vector, a: Vector, b: Vector, c: Vector) -> (f32, f32, bool) {
+    let v0 = b - a;
+    let v1 = c - a;
+    let v2 = p - a;
+
+    let d00 = v0.dot(v0);
+    let d01 = v0.dot(v1);
+    let d11 = v1.dot(v1);
+    let d20 = v2.dot(v0);
+    let d21 = v2.dot(v1);
+
+    let denom = d00 * d11 - d01 * d01;
+
+    // denom is d00 * d11 * sin² of the angle at a, so the threshold scales with the triangle.
+    let degenerate = denom <= f32::EPSILON * d00 * d11;
+
+    ((d11 * d20 - d01 * d21) / denom, (d00 * d21 - d01 * d20) / denom, degenerate)
 }
 
+use std::f32;
 use std::ops::Add;
 use std::ops::Sub;
 use std::ops::Mul;
 use std::ops::Neg;
 use std::cmp::Ordering;
+#[cfg(feature = "hash")]
+use std::hash::{Hash, Hasher};
 use math::Interpolate;
 
 use mrusty::*;
@@ -394,6 +1233,57 @@ impl Neg for Vector {
     }
 }
 
+/// A `Vector` wrapper useful as an exact-match `HashMap` or `HashSet` key, e.g. when deduplicating
+/// mesh vertices. Only available with the `hash` feature.
+///
+/// Keys compare and hash the bit patterns of the coordinates instead of their values, with `-0.0`
+/// treated like `0.0`. Vectors that differ by rounding are therefore different keys, while a
+/// vector containing NaN is equal to a key with the exact same NaN bits, unlike with `==`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "hash")]
+/// # {
+/// # use std::collections::HashSet;
+/// # use anima_engine::math::Vector;
+/// # use anima_engine::math::VectorKey;
+/// let mut set = HashSet::new();
+///
+/// set.insert(VectorKey(Vector::new(1.0, 2.0, 3.0)));
+///
+/// assert!(set.contains(&VectorKey(Vector::new(1.0, 2.0, 3.0))));
+/// # }
+/// ```
+#[cfg(feature = "hash")]
+#[derive(Clone, Copy, Debug)]
+pub struct VectorKey(pub Vector);
+
+#[cfg(feature = "hash")]
+impl VectorKey {
+    fn bits(&self) -> [u32; 3] {
+        // Adding 0.0 turns -0.0 into 0.0.
+        [(self.0.x + 0.0).to_bits(), (self.0.y + 0.0).to_bits(), (self.0.z + 0.0).to_bits()]
+    }
+}
+
+#[cfg(feature = "hash")]
+impl PartialEq for VectorKey {
+    fn eq(&self, other: &VectorKey) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+#[cfg(feature = "hash")]
+impl Eq for VectorKey {}
+
+#[cfg(feature = "hash")]
+impl Hash for VectorKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+
 impl PartialOrd for Vector {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.len().partial_cmp(&other.len())
@@ -416,13 +1306,14 @@ mrusty_class!(Vector, {
     });
 
     def_self!("from_a", |mruby, _slf: Value, array: Vec| {
-        let x = array[0].to_f64().unwrap();
-        let y = array[1].to_f64().unwrap();
-        let z = array[2].to_f64().unwrap();
-
-        let vector = Vector::new(x as f32, y as f32, z as f32);
+        if array.len() != 3 {
+            return mruby.raise("ArgumentError", "Array should contain 3 items");
+        }
 
-        mruby.obj(vector)
+        match (array[0].to_f64(), array[1].to_f64(), array[2].to_f64()) {
+            (Ok(x), Ok(y), Ok(z)) => mruby.obj(Vector::new(x as f32, y as f32, z as f32)),
+            _ => mruby.raise("TypeError", "expecting Array of Floats")
+        }
     });
 
     def_self!("uniform", |mruby, _slf: Value, value: f64| {
@@ -432,6 +1323,102 @@ mrusty_class!(Vector, {
         mruby.obj(vector)
     });
 
+    def_self!("barycentric", |mruby, _slf: Value, p: Value, a: Value, b: Value, c: Value| {
+        let p = try_obj!(mruby, p, Vector);
+        let a = try_obj!(mruby, a, Vector);
+        let b = try_obj!(mruby, b, Vector);
+        let c = try_obj!(mruby, c, Vector);
+
+        match try_barycentric(p, a, b, c) {
+            Some((u, v, w)) => {
+                mruby.array(vec![mruby.float(u as f64), mruby.float(v as f64),
+                                 mruby.float(w as f64)])
+            }
+            None => mruby.nil()
+        }
+    });
+
+    def_self!("from_barycentric", |mruby, _slf: Value, a: Value, b: Value, c: Value, weights: Vec| {
+        let a = try_obj!(mruby, a, Vector);
+        let b = try_obj!(mruby, b, Vector);
+        let c = try_obj!(mruby, c, Vector);
+
+        if weights.len() != 3 {
+            return mruby.raise("ArgumentError", "weights should contain 3 items");
+        }
+
+        let (u, v, w) = match (weights[0].to_f64(), weights[1].to_f64(), weights[2].to_f64()) {
+            (Ok(u), Ok(v), Ok(w)) => (u as f32, v as f32, w as f32),
+            _ => return mruby.raise("TypeError", "expecting Array of Floats")
+        };
+
+        let vector = Vector::from_barycentric(a, b, c, (u, v, w));
+
+        mruby.obj(vector)
+    });
+
+    def_self!("from_spherical", |mruby, _slf: Value, radius: f64, theta: f64, phi: f64| {
+        mruby.obj(Vector::from_spherical(radius as f32, theta as f32, phi as f32))
+    });
+
+    def_self!("centroid", |mruby, _slf: Value, points: Vec| {
+        let points: Result<Vec<Vector>, _> = points.iter().map(|point| {
+            point.to_obj::<Vector>().map(|point| (*point).clone())
+        }).collect();
+
+        match points {
+            Ok(points) => mruby.obj(Vector::centroid(&points)),
+            Err(_)     => mruby.raise("TypeError", "expecting Array of Vectors")
+        }
+    });
+
+    def_self!("weighted_sum", |mruby, _slf: Value, points: Vec, weights: Vec| {
+        if points.len() != weights.len() {
+            return mruby.raise("ArgumentError", "points and weights should have the same size");
+        }
+
+        let points: Result<Vec<Vector>, _> = points.iter().map(|point| {
+            point.to_obj::<Vector>().map(|point| (*point).clone())
+        }).collect();
+
+        let weights: Option<Vec<f32>> = weights.iter().map(|weight| {
+            if weight.class().to_str() == "Float" {
+                weight.to_f64().ok().map(|weight| weight as f32)
+            } else {
+                None
+            }
+        }).collect();
+
+        match (points, weights) {
+            (Ok(points), Some(weights)) => mruby.obj(Vector::weighted_sum(&points, &weights)),
+            _ => mruby.raise("TypeError", "expecting Array of Vectors and Array of Floats")
+        }
+    });
+
+    def_self!("from_angle_xz", |mruby, _slf: Value, radians: f64| {
+        mruby.obj(Vector::from_angle_xz(radians as f32))
+    });
+
+    def_self!("from_angle_xy", |mruby, _slf: Value, radians: f64| {
+        mruby.obj(Vector::from_angle_xy(radians as f32))
+    });
+
+    def_self!("smoothstep", |mruby, _slf: Value, edge0: Value, edge1: Value, x: Value| {
+        let edge0 = try_obj!(mruby, edge0, Vector);
+        let edge1 = try_obj!(mruby, edge1, Vector);
+        let x = try_obj!(mruby, x, Vector);
+
+        mruby.obj(Vector::smoothstep(edge0, edge1, x))
+    });
+
+    def_self!("smootherstep", |mruby, _slf: Value, edge0: Value, edge1: Value, x: Value| {
+        let edge0 = try_obj!(mruby, edge0, Vector);
+        let edge1 = try_obj!(mruby, edge1, Vector);
+        let x = try_obj!(mruby, x, Vector);
+
+        mruby.obj(Vector::smootherstep(edge0, edge1, x))
+    });
+
     def_self!("zero", |mruby, _slf: Value| {
         mruby.obj(Vector::zero())
     });
@@ -464,7 +1451,9 @@ mrusty_class!(Vector, {
         mruby.obj(Vector::up())
     });
 
-    def!("==", |mruby, slf: Vector, other: Vector| {
+    def!("==", |mruby, slf: Vector, other: Value| {
+        let other = try_obj!(other, Vector, else mruby.bool(false));
+
         let result = slf.x == other.x &&
                      slf.y == other.y &&
                      slf.z == other.z;
@@ -478,12 +1467,16 @@ mrusty_class!(Vector, {
         mruby.string(&string)
     });
 
-    def!("+", |mruby, slf: Vector, other: Vector| {
-        mruby.obj((*slf).clone() + (*other).clone())
+    def!("+", |mruby, slf: Vector, other: Value| {
+        let other = try_obj!(mruby, other, Vector);
+
+        mruby.obj((*slf).clone() + other)
     });
 
-    def!("-", |mruby, slf: Vector, other: Vector| {
-        mruby.obj((*slf).clone() - (*other).clone())
+    def!("-", |mruby, slf: Vector, other: Value| {
+        let other = try_obj!(mruby, other, Vector);
+
+        mruby.obj((*slf).clone() - other)
     });
 
     def!("*", |mruby, slf: Vector, other: Value| {
@@ -518,6 +1511,18 @@ mrusty_class!(Vector, {
         mruby.float(slf.z as f64)
     });
 
+    def!("with_x", |mruby, slf: Vector, x: f64| {
+        mruby.obj(slf.with_x(x as f32))
+    });
+
+    def!("with_y", |mruby, slf: Vector, y: f64| {
+        mruby.obj(slf.with_y(y as f32))
+    });
+
+    def!("with_z", |mruby, slf: Vector, z: f64| {
+        mruby.obj(slf.with_z(z as f32))
+    });
+
     def!("len", |mruby, slf: Vector| {
         mruby.float(slf.len() as f64)
     });
@@ -526,36 +1531,179 @@ mrusty_class!(Vector, {
         mruby.obj(slf.norm())
     });
 
-    def!("dot", |mruby, slf: Vector, other: Vector| {
-        mruby.float(slf.dot((*other).clone()) as f64)
+    def!("normalize_or", |mruby, slf: Vector, fallback: Value| {
+        let fallback = try_obj!(mruby, fallback, Vector);
+
+        mruby.obj(slf.normalize_or(fallback))
+    });
+
+    def!("clamp_length", |mruby, slf: Vector, max: f64| {
+        mruby.obj(slf.clamp_length(max as f32))
+    });
+
+    def!("dot", |mruby, slf: Vector, other: Value| {
+        let other = try_obj!(mruby, other, Vector);
+
+        mruby.float(slf.dot(other) as f64)
+    });
+
+    def!("cross", |mruby, slf: Vector, other: Value| {
+        let other = try_obj!(mruby, other, Vector);
+
+        mruby.obj(slf.cross(other))
+    });
+
+    def!("cross_xz", |mruby, slf: Vector, other: Value| {
+        let other = try_obj!(mruby, other, Vector);
+
+        mruby.float(slf.cross_xz(other) as f64)
+    });
+
+    def!("cross_xy", |mruby, slf: Vector, other: Value| {
+        let other = try_obj!(mruby, other, Vector);
+
+        mruby.float(slf.cross_xy(other) as f64)
+    });
+
+    def!("orthonormal_basis", |mruby, slf: Vector| {
+        let (first, second, third) = slf.orthonormal_basis();
+
+        mruby.array(vec![mruby.obj(first), mruby.obj(second), mruby.obj(third)])
+    });
+
+    def!("rot", |mruby, slf: Vector, quternion: Value| {
+        let quternion = try_obj!(mruby, quternion, Quaternion);
+
+        mruby.obj(slf.rot(quternion))
+    });
+
+    def!("rot_around", |mruby, slf: Vector, quternion: Value, point: Value| {
+        let quternion = try_obj!(mruby, quternion, Quaternion);
+        let point = try_obj!(mruby, point, Vector);
+
+        mruby.obj(slf.rot_around(quternion, point))
+    });
+
+    def!("rotate_x", |mruby, slf: Vector, radians: f64| {
+        mruby.obj(slf.rotate_x(radians as f32))
+    });
+
+    def!("rotate_y", |mruby, slf: Vector, radians: f64| {
+        mruby.obj(slf.rotate_y(radians as f32))
+    });
+
+    def!("rotate_z", |mruby, slf: Vector, radians: f64| {
+        mruby.obj(slf.rotate_z(radians as f32))
+    });
+
+    def!("angle", |mruby, slf: Vector, other: Value| {
+        let other = try_obj!(mruby, other, Vector);
+
+        mruby.float(slf.angle(other) as f64)
+    });
+
+    def!("dist", |mruby, slf: Vector, other: Value| {
+        let other = try_obj!(mruby, other, Vector);
+
+        mruby.float(slf.dist(other) as f64)
+    });
+
+    def!("manhattan_dist", |mruby, slf: Vector, other: Value| {
+        let other = try_obj!(mruby, other, Vector);
+
+        mruby.float(slf.manhattan_dist(other) as f64)
+    });
+
+    def!("chebyshev_dist", |mruby, slf: Vector, other: Value| {
+        let other = try_obj!(mruby, other, Vector);
+
+        mruby.float(slf.chebyshev_dist(other) as f64)
+    });
+
+    def!("dist_to_segment", |mruby, slf: Vector, a: Value, b: Value| {
+        let a = try_obj!(mruby, a, Vector);
+        let b = try_obj!(mruby, b, Vector);
+
+        mruby.float(slf.dist_to_segment(a, b) as f64)
+    });
+
+    def!("dist_to_line", |mruby, slf: Vector, a: Value, b: Value| {
+        let a = try_obj!(mruby, a, Vector);
+        let b = try_obj!(mruby, b, Vector);
+
+        mruby.float(slf.dist_to_line(a, b) as f64)
+    });
+
+    def!("inverse_lerp", |mruby, slf: Vector, a: Value, b: Value| {
+        let a = try_obj!(mruby, a, Vector);
+        let b = try_obj!(mruby, b, Vector);
+
+        mruby.float(slf.inverse_lerp(a, b) as f64)
+    });
+
+    def!("project_on_plane", |mruby, slf: Vector, normal: Value| {
+        let normal = try_obj!(mruby, normal, Vector);
+
+        mruby.obj(slf.project_on_plane(normal))
+    });
+
+    def!("reflect", |mruby, slf: Vector, normal: Value| {
+        let normal = try_obj!(mruby, normal, Vector);
+
+        mruby.obj(slf.reflect(normal))
+    });
+
+    def!("max_component", |mruby, slf: Vector| {
+        mruby.float(slf.max_component() as f64)
+    });
+
+    def!("min_component", |mruby, slf: Vector| {
+        mruby.float(slf.min_component() as f64)
+    });
+
+    def!("sum", |mruby, slf: Vector| {
+        mruby.float(slf.sum() as f64)
     });
 
-    def!("cross", |mruby, slf: Vector, other: Vector| {
-        mruby.obj(slf.cross((*other).clone()))
+    def!("snap_to_grid", |mruby, slf: Vector, cell: Value| {
+        let cell = try_obj!(mruby, cell, Vector);
+
+        mruby.obj(slf.snap_to_grid(cell))
+    });
+
+    def!("snap", |mruby, slf: Vector, size: f64| {
+        mruby.obj(slf.snap(size as f32))
     });
 
-    def!("rot", |mruby, slf: Vector, quternion: Quaternion| {
-        mruby.obj(slf.rot((*quternion).clone()))
+    def!("finite?", |mruby, slf: Vector| {
+        mruby.bool(slf.is_finite())
     });
 
-    def!("rot_around", |mruby, slf: Vector, quternion: Quaternion, point: Vector| {
-        mruby.obj(slf.rot_around((*quternion).clone(), (*point).clone()))
+    def!("nan?", |mruby, slf: Vector| {
+        mruby.bool(slf.is_nan())
     });
 
-    def!("angle", |mruby, slf: Vector, other: Vector| {
-        mruby.float(slf.angle((*other).clone()) as f64)
+    def!("to_spherical", |mruby, slf: Vector| {
+        let (radius, theta, phi) = slf.to_spherical();
+
+        mruby.array(vec![mruby.float(radius as f64), mruby.float(theta as f64),
+                         mruby.float(phi as f64)])
     });
 
-    def!("dist", |mruby, slf: Vector, other: Vector| {
-        mruby.float(slf.dist((*other).clone()) as f64)
+    def!("angle_xz", |mruby, slf: Vector| {
+        mruby.float(slf.angle_xz() as f64)
     });
 
-    def!("<=>", |mruby, slf: Vector, other: Vector| {
+    def!("<=>", |mruby, slf: Vector, other: Value| {
+        let other = try_obj!(mruby, other, Vector);
+
         mruby.float((slf.len() - other.len()) as f64)
     });
 
-    def!("interpolate", |mruby, slf: Vector, other: Vector, ratio: f64| {
-        mruby.obj(slf.interpolate((*other).clone(), ratio as f32))
+    def!("interpolate", |mruby, slf: Vector, other: Value, ratio: f64| {
+        let other = try_obj!(mruby, other, Vector);
+
+        mruby.obj(slf.interpolate(other, ratio as f32))
     });
 });
 
@@ -567,6 +1715,46 @@ mod tests {
     use super::super::Quaternion;
 
     describe!(Vector, (Quaternion), "
+      context 'when passed wrong types' do
+        it 'raises TypeError on #dot' do
+          expect { Vector.one.dot [1.0, 1.0, 1.0] }.to raise_error TypeError
+        end
+
+        it 'raises TypeError on #cross' do
+          expect { Vector.one.cross 1.0 }.to raise_error TypeError
+        end
+
+        it 'raises TypeError on #cross_xz' do
+          expect { Vector.one.cross_xz 1.0 }.to raise_error TypeError
+        end
+
+        it 'raises TypeError on #+ and #-' do
+          expect { Vector.one + [1.0, 2.0, 3.0] }.to raise_error TypeError
+          expect { Vector.one - 1.0 }.to raise_error TypeError
+        end
+
+        it 'raises TypeError on #rot' do
+          expect { Vector.one.rot Vector.up }.to raise_error TypeError
+        end
+
+        it 'raises TypeError on #dist and #angle' do
+          expect { Vector.one.dist nil }.to raise_error TypeError
+          expect { Vector.one.angle [1.0, 2.0, 3.0] }.to raise_error TypeError
+        end
+
+        it 'raises TypeError on #dist_to_segment' do
+          expect { Vector.one.dist_to_segment Vector.zero, 1.0 }.to raise_error TypeError
+        end
+
+        it 'is not equal to other types on #==' do
+          expect(Vector.one == [1.0, 1.0, 1.0]).to be false
+        end
+
+        it 'raises TypeError on .from_a' do
+          expect { Vector.from_a [1.0, nil, 1.0] }.to raise_error TypeError
+        end
+      end
+
       context 'when default' do
         it 'creates zero vector' do
           expect(Vector.zero).to eql Vector.uniform 0.0
@@ -620,6 +1808,18 @@ mod tests {
           expect(subject.to_s).to eql '<Vector: @x=1 @y=1 @z=1>'
         end
 
+        it 'replaces x on #with_x' do
+          expect(subject.with_x 5.0).to eql Vector.new 5.0, 1.0, 1.0
+        end
+
+        it 'replaces y on #with_y' do
+          expect(subject.with_y 5.0).to eql Vector.new 1.0, 5.0, 1.0
+        end
+
+        it 'replaces z on #with_z' do
+          expect(subject.with_z 5.0).to eql Vector.new 1.0, 1.0, 5.0
+        end
+
         it 'returns vector length on #len' do
           expect(subject.len).to be_within(0.000001).of 1.73205
         end
@@ -632,6 +1832,11 @@ mod tests {
           expect(norm.z).to be_within(0.000001).of 0.57735
         end
 
+        it 'clamps length on #clamp_length' do
+          expect(Vector.new(0.0, 4.0, 0.0).clamp_length 2.0).to eql Vector.new 0.0, 2.0, 0.0
+          expect(Vector.zero.clamp_length 2.0).to eql Vector.zero
+        end
+
         it 'computes dot product on #dot' do
           expect(subject.dot(Vector.new 1.0, 2.0, 3.0)).to eql 6.0
         end
@@ -640,6 +1845,11 @@ mod tests {
           expect(subject.cross(Vector.new 1.0, 2.0, 3.0)).to eql Vector.new 1.0, -2.0, 1.0
         end
 
+        it 'computes 2D cross products on #cross_xz and #cross_xy' do
+          expect(subject.cross_xz(Vector.new 1.0, 2.0, 3.0)).to eql -2.0
+          expect(subject.cross_xy(Vector.new 1.0, 2.0, 3.0)).to eql 1.0
+        end
+
         it 'rotates on #rot' do
           up = subject.cross(Vector.new(-1.0, 0.0, 1.0))
           rotated = subject.rot Quaternion.rotation(up, Math::PI)
@@ -657,6 +1867,14 @@ mod tests {
           expect(rotated.z).to be_within(0.000001).of 1.0
         end
 
+        it 'rotates around y on #rotate_y' do
+          rotated = subject.rotate_y Math::PI
+
+          expect(rotated.x).to be_within(0.000001).of -1.0
+          expect(rotated.y).to be_within(0.000001).of 1.0
+          expect(rotated.z).to be_within(0.000001).of -1.0
+        end
+
         it 'computes angle on #angle' do
           expect(subject.angle(Vector.new -1.0, -1.0, -1.0)).to be_within(0.01).of 3.14
         end
@@ -665,6 +1883,44 @@ mod tests {
           expect(subject.dist(Vector.new 1.0, -1.0, 1.0)).to eql 2.0
         end
 
+        it 'projects on plane on #project_on_plane' do
+          expect(subject.project_on_plane Vector.up).to eql Vector.new 1.0, 0.0, 1.0
+        end
+
+        it 'reflects about plane on #reflect' do
+          expect(subject.reflect Vector.up).to eql Vector.new 1.0, -1.0, 1.0
+        end
+
+        it 'returns largest component on #max_component' do
+          expect(Vector.new(-3.0, 2.0, -1.0).max_component).to eql 2.0
+        end
+
+        it 'returns smallest component on #min_component' do
+          expect(Vector.new(-3.0, 2.0, -1.0).min_component).to eql -3.0
+        end
+
+        it 'sums components on #sum' do
+          expect(subject.sum).to eql 3.0
+        end
+
+        it 'snaps to the grid on #snap_to_grid' do
+          expect((subject * 1.4).snap_to_grid Vector.new(1.0, 0.0, 0.5)).to eql(
+            Vector.new 1.0, 1.4, 1.5
+          )
+        end
+
+        it 'snaps to a uniform grid on #snap' do
+          expect((subject * 1.4).snap 2.0).to eql Vector.uniform 2.0
+        end
+
+        it 'is finite on #finite?' do
+          expect(subject.finite?).to be true
+        end
+
+        it 'is not NaN on #nan?' do
+          expect(subject.nan?).to be false
+        end
+
         it 'adds vectors on #+' do
           expect(subject + Vector.new(1.0, 2.0, 3.0)).to eql Vector.new 2.0, 3.0, 4.0
         end
@@ -690,6 +1946,120 @@ mod tests {
         end
       end
 
+      context 'when zero' do
+        subject { Vector.zero }
+
+        it 'normalizes to NaN on #nan?' do
+          expect(subject.norm.nan?).to be true
+        end
+
+        it 'falls back on #normalize_or' do
+          expect(subject.normalize_or Vector.up).to eql Vector.up
+        end
+
+        it 'returns zeroed angles on #to_spherical' do
+          expect(subject.to_spherical).to eql [0.0, 0.0, 0.0]
+        end
+      end
+
+      context 'when forward' do
+        subject { Vector.forward }
+
+        it 'builds a right-handed basis on #orthonormal_basis' do
+          expect(subject.orthonormal_basis).to eql [
+            Vector.forward, Vector.up, -Vector.left
+          ]
+        end
+      end
+
+      context 'when spherical' do
+        it 'converts up to the pole on #to_spherical' do
+          expect(Vector.up.to_spherical).to eql [1.0, 0.0, 0.0]
+        end
+
+        it 'converts the pole from spherical on .from_spherical' do
+          expect(Vector.from_spherical 2.0, 0.0, 0.0).to eql Vector.new 0.0, 2.0, 0.0
+        end
+
+        it 'converts zero angle to left on .from_angle_xz' do
+          expect(Vector.from_angle_xz 0.0).to eql Vector.left
+        end
+
+        it 'recovers heading on #angle_xz' do
+          expect(Vector.from_angle_xz(1.0).angle_xz).to be_within(0.000001).of 1.0
+        end
+      end
+
+      context 'when averaging' do
+        it 'computes the mean on .centroid' do
+          expect(Vector.centroid [Vector.zero, Vector.uniform(2.0)]).to eql Vector.one
+        end
+
+        it 'returns zero for no points on .centroid' do
+          expect(Vector.centroid []).to eql Vector.zero
+        end
+
+        it 'blends by weights on .weighted_sum' do
+          expect(Vector.weighted_sum [Vector.zero, Vector.uniform(2.0)], [0.25, 0.5]).to eql(
+            Vector.one
+          )
+        end
+
+        it 'raises ArgumentError for mismatched sizes on .weighted_sum' do
+          expect { Vector.weighted_sum [Vector.zero], [] }.to raise_error ArgumentError
+        end
+      end
+
+      context 'when near a segment' do
+        let(:a) { Vector.zero }
+        let(:b) { Vector.new 2.0, 0.0, 0.0 }
+        subject { Vector.new 5.0, 4.0, 0.0 }
+
+        it 'measures to the closest end on #dist_to_segment' do
+          expect(subject.dist_to_segment a, b).to eql 5.0
+        end
+
+        it 'measures perpendicularly on #dist_to_line' do
+          expect(subject.dist_to_line a, b).to eql 4.0
+        end
+
+        it 'recovers the projected ratio on #inverse_lerp' do
+          expect(subject.inverse_lerp a, b).to eql 2.5
+        end
+      end
+
+      context 'when in a triangle' do
+        let(:a) { Vector.zero }
+        let(:b) { Vector.new 2.0, 0.0, 0.0 }
+        let(:c) { Vector.new 0.0, 2.0, 0.0 }
+
+        it 'computes weights on .barycentric' do
+          expect(Vector.barycentric Vector.new(0.5, 0.5, 0.0), a, b, c).to eql [0.5, 0.25, 0.25]
+        end
+
+        it 'returns nil for degenerate triangles on .barycentric' do
+          expect(Vector.barycentric Vector.one, a, b, b * 2.0).to be_nil
+        end
+
+        it 'reconstructs point on .from_barycentric' do
+          point = Vector.from_barycentric a, b, c, [0.5, 0.25, 0.25]
+
+          expect(point).to eql Vector.new 0.5, 0.5, 0.0
+        end
+      end
+
+      context 'when stepping' do
+        let(:x) { Vector.new -1.0, 0.5, 2.0 }
+
+        it 'steps component-wise on .smoothstep' do
+          expect(Vector.smoothstep Vector.zero, Vector.one, x).to eql Vector.new 0.0, 0.5, 1.0
+        end
+
+        it 'steps component-wise on .smootherstep' do
+          expect(Vector.smootherstep Vector.zero, Vector.one, x).to eql Vector.new 0.0, 0.5, 1.0
+        end
+      end
+
       context 'when initialized from array' do
         subject { Vector.from_a [1.0, 2.0, 3.0] }
 
@@ -0,0 +1,158 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+use math::{Matrix, Transform, Vector};
+
+/// A shared reference to a `Node`, as stored by its parent.
+pub type NodeRef = Rc<RefCell<Node>>;
+
+/// A scene-graph `struct` holding a local `Transform` relative to its parent.
+///
+/// The world matrix is computed lazily and cached. Changing the local transform or the parent
+/// marks the node and all its descendants dirty. Since computing a node's world matrix first
+/// computes its parent's, a clean node always has clean ancestors; invalidation can therefore
+/// stop at nodes that are already dirty.
+///
+/// # Examples
+///
+/// ```
+/// # use anima_engine::math::Quaternion;
+/// # use anima_engine::math::Transform;
+/// # use anima_engine::math::Vector;
+/// # use anima_engine::scene::Node;
+/// let parent = Node::new(Transform::ident());
+/// let child = Node::new(Transform::new(Vector::up(), Quaternion::ident(), Vector::one()));
+///
+/// Node::add_child(&parent, child.clone());
+///
+/// assert_eq!(child.borrow().world_position(), Vector::up());
+///
+/// parent.borrow_mut().set_local(Transform::new(Vector::up(), Quaternion::ident(),
+///                                              Vector::one()));
+///
+/// assert_eq!(child.borrow().world_position(), Vector::new(0.0, 2.0, 0.0));
+/// ```
+#[derive(Debug)]
+pub struct Node {
+    local: Transform,
+    parent: Option<Weak<RefCell<Node>>>,
+    children: Vec<NodeRef>,
+    world: Cell<Option<Matrix>>
+}
+
+impl Node {
+    /// Creates a parentless node using a local transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Transform;
+    /// # use anima_engine::scene::Node;
+    /// let node = Node::new(Transform::ident());
+    ///
+    /// assert!(node.borrow().parent().is_none());
+    /// ```
+    pub fn new(local: Transform) -> NodeRef {
+        Rc::new(RefCell::new(Node {
+            local: local,
+            parent: None,
+            children: vec![],
+            world: Cell::new(None)
+        }))
+    }
+
+    /// Attaches `child` to `parent`, detaching it from its previous parent first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anima_engine::math::Transform;
+    /// # use anima_engine::scene::Node;
+    /// let parent = Node::new(Transform::ident());
+    /// let child = Node::new(Transform::ident());
+    ///
+    /// Node::add_child(&parent, child.clone());
+    ///
+    /// assert_eq!(parent.borrow().children().len(), 1);
+    /// assert!(child.borrow().parent().is_some());
+    /// ```
+    pub fn add_child(parent: &NodeRef, child: NodeRef) {
+        if let Some(old) = child.borrow().parent() {
+            old.borrow_mut().children.retain(|c| !Rc::ptr_eq(c, &child));
+        }
+
+        {
+            let mut node = child.borrow_mut();
+
+            node.parent = Some(Rc::downgrade(parent));
+            node.invalidate();
+        }
+
+        parent.borrow_mut().children.push(child);
+    }
+
+    /// Returns the local transform of a node.
+    pub fn local(&self) -> Transform {
+        self.local
+    }
+
+    /// Replaces the local transform of a node, invalidating its world matrix and those of its
+    /// descendants.
+    pub fn set_local(&mut self, local: Transform) {
+        self.local = local;
+
+        self.invalidate();
+    }
+
+    /// Returns the parent of a node, if any.
+    pub fn parent(&self) -> Option<NodeRef> {
+        self.parent.as_ref().and_then(|parent| parent.upgrade())
+    }
+
+    /// Returns the children of a node.
+    pub fn children(&self) -> &[NodeRef] {
+        &self.children
+    }
+
+    /// Computes the world matrix of a node (the parent's world matrix times the local one), or
+    /// returns the cached one if nothing changed since.
+    pub fn world_matrix(&self) -> Matrix {
+        if let Some(world) = self.world.get() {
+            return world;
+        }
+
+        let local = self.local.to_matrix();
+        let world = match self.parent() {
+            Some(parent) => parent.borrow().world_matrix() * local,
+            None         => local
+        };
+
+        self.world.set(Some(world));
+
+        world
+    }
+
+    /// Computes the position of a node in world space.
+    pub fn world_position(&self) -> Vector {
+        self.world_matrix() * Vector::zero()
+    }
+
+    fn invalidate(&self) {
+        if self.world.get().is_none() {
+            return;
+        }
+
+        self.world.set(None);
+
+        for child in &self.children {
+            child.borrow().invalidate();
+        }
+    }
+}
@@ -9,10 +9,24 @@
 
 use mrusty::*;
 
+use super::math::Aabb;
 use super::math::Bezier;
+use super::math::BezierPath;
+use super::math::Camera;
+use super::math::Color;
+use super::math::Frustum;
 use super::math::Interpolator;
 use super::math::Matrix;
+use super::math::MatrixStack;
+use super::math::PhasedInterpolator;
+use super::math::Plane;
 use super::math::Quaternion;
+use super::math::Ray;
+use super::math::Scalar;
+use super::math::SpatialHash;
+use super::math::Spring;
+use super::math::Timeline;
+use super::math::Transform;
 use super::math::Vector;
 
 /// A `fn` that returns a new mruby state with `require`able Anima API.
@@ -20,10 +34,24 @@ use super::math::Vector;
 /// API is structured in virtual mruby files thus:
 ///
 /// * `math`
+///   * `Aabb`
 ///   * `Bezier`
+///   * `BezierPath`
+///   * `Camera`
+///   * `Color`
+///   * `Frustum`
 ///   * `Interpolator`
 ///   * `Matrix`
+///   * `MatrixStack`
+///   * `PhasedInterpolator`
+///   * `Plane`
 ///   * `Quaternion`
+///   * `Ray`
+///   * `Scalar`
+///   * `SpatialHash`
+///   * `Spring`
+///   * `Timeline`
+///   * `Transform`
 ///   * `Vector`
 ///
 /// # Examples
@@ -38,10 +66,24 @@ use super::math::Vector;
 pub fn get_mruby() -> MrubyType {
     let mruby = Mruby::new();
 
+    mruby.def_file::<Aabb>("math");
     mruby.def_file::<Bezier>("math");
+    mruby.def_file::<BezierPath>("math");
+    mruby.def_file::<Camera>("math");
+    mruby.def_file::<Color>("math");
+    mruby.def_file::<Frustum>("math");
     mruby.def_file::<Interpolator>("math");
     mruby.def_file::<Matrix>("math");
+    mruby.def_file::<MatrixStack>("math");
+    mruby.def_file::<PhasedInterpolator>("math");
+    mruby.def_file::<Plane>("math");
     mruby.def_file::<Quaternion>("math");
+    mruby.def_file::<Ray>("math");
+    mruby.def_file::<Scalar>("math");
+    mruby.def_file::<SpatialHash>("math");
+    mruby.def_file::<Spring>("math");
+    mruby.def_file::<Timeline>("math");
+    mruby.def_file::<Transform>("math");
     mruby.def_file::<Vector>("math");
 
     mruby
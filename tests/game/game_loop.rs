@@ -0,0 +1,256 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+extern crate anima_engine;
+
+use std::cell::{Cell, RefCell};
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use self::anima_engine::game::{DeltaSmoother, FixedTimestep, Game, GameLoop};
+
+struct MockGame {
+    step: Duration,
+    updates: Cell<u32>,
+    max_updates: u32
+}
+
+impl Game for MockGame {
+    fn update(&self, dt: Duration) -> bool {
+        assert_eq!(dt, self.step);
+
+        self.updates.set(self.updates.get() + 1);
+
+        self.updates.get() < self.max_updates
+    }
+}
+
+struct NoopGame {
+    updates: Cell<u64>
+}
+
+impl Game for NoopGame {
+    fn update(&self, _: Duration) -> bool {
+        self.updates.set(self.updates.get() + 1);
+
+        true
+    }
+}
+
+#[test]
+fn test_run_until() {
+    let game_loop = GameLoop::new(NoopGame { updates: Cell::new(0) });
+
+    game_loop.run_until(|frames, _| frames == 10);
+
+    assert_eq!(game_loop.game.updates.get(), 10);
+}
+
+struct LifecycleGame {
+    events: RefCell<Vec<&'static str>>
+}
+
+impl Game for LifecycleGame {
+    fn start(&self) {
+        self.events.borrow_mut().push("start");
+    }
+
+    fn update(&self, _: Duration) -> bool {
+        self.events.borrow_mut().push("update");
+
+        self.events.borrow().len() < 3
+    }
+
+    fn stop(&self) {
+        self.events.borrow_mut().push("stop");
+    }
+}
+
+#[test]
+fn test_lifecycle() {
+    let game_loop = GameLoop::new(LifecycleGame { events: RefCell::new(vec![]) });
+
+    game_loop.run();
+
+    assert_eq!(*game_loop.game.events.borrow(), vec!["start", "update", "update", "stop"]);
+}
+
+struct PanickingGame {
+    updates: Cell<u32>,
+    stopped: Cell<bool>
+}
+
+impl Game for PanickingGame {
+    fn update(&self, _: Duration) -> bool {
+        self.updates.set(self.updates.get() + 1);
+
+        if self.updates.get() == 3 {
+            panic!("update failed");
+        }
+
+        true
+    }
+
+    fn stop(&self) {
+        self.stopped.set(true);
+    }
+}
+
+#[test]
+fn test_run_catching() {
+    let game_loop = GameLoop::new(AssertUnwindSafe(PanickingGame {
+        updates: Cell::new(0),
+        stopped: Cell::new(false)
+    }));
+
+    let error = game_loop.run_catching().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<&str>(), Some(&"update failed"));
+    assert_eq!(game_loop.game.updates.get(), 3);
+    assert!(game_loop.game.stopped.get());
+}
+
+#[test]
+fn test_run_catching_ok() {
+    let game_loop = GameLoop::new(AssertUnwindSafe(LifecycleGame {
+        events: RefCell::new(vec![])
+    }));
+
+    assert!(game_loop.run_catching().is_ok());
+    assert_eq!(*game_loop.game.events.borrow(), vec!["start", "update", "update", "stop"]);
+}
+
+#[test]
+fn test_fixed_timestep() {
+    let mut t = FixedTimestep::new(Duration::from_millis(4));
+    let mut updates = 0;
+
+    for _ in 0..10 {
+        t.advance(Duration::from_millis(3));
+
+        while t.consume() {
+            updates += 1;
+        }
+
+        assert!((0.0..1.0).contains(&t.alpha()));
+    }
+
+    assert_eq!(updates, 7);
+    assert_eq!(t.accumulator, Duration::from_millis(2));
+    assert_eq!(t.alpha(), 0.5);
+}
+
+#[test]
+fn test_delta_smoother() {
+    fn variance(deltas: &[f64]) -> f64 {
+        let mean = deltas.iter().sum::<f64>() / deltas.len() as f64;
+
+        deltas.iter().map(|delta| (delta - mean) * (delta - mean)).sum::<f64>() /
+            deltas.len() as f64
+    }
+
+    // A mock clock around 60 FPS, jittering by up to 5 ms.
+    let jitter = [0, 5, -4, 3, -5, 1, 4, -2, -3, 5, -1, 2];
+    let raw: Vec<Duration> = (0..60).map(|i| {
+        Duration::from_micros((16_667 + jitter[i % jitter.len()] * 1_000) as u64)
+    }).collect();
+
+    let mut smoother = DeltaSmoother::new(4);
+    let smoothed: Vec<Duration> = raw.iter().map(|&delta| smoother.smooth(delta)).collect();
+
+    let raw: Vec<f64> = raw.iter().map(|delta| delta.as_secs_f64()).collect();
+    let smoothed: Vec<f64> = smoothed.iter().map(|delta| delta.as_secs_f64()).collect();
+
+    assert!(variance(&smoothed) < variance(&raw) / 2.0);
+
+    let raw_total: f64 = raw.iter().sum();
+    let smoothed_total: f64 = smoothed.iter().sum();
+
+    assert!((raw_total - smoothed_total).abs() < 0.02);
+}
+
+#[test]
+fn test_run_smoothed() {
+    let game_loop = GameLoop::new_smoothed(NoopGame { updates: Cell::new(0) }, 4);
+
+    assert_eq!(game_loop.smoothing, 4);
+
+    game_loop.run_until(|frames, _| frames == 10);
+
+    assert_eq!(game_loop.game.updates.get(), 10);
+}
+
+#[test]
+#[should_panic]
+fn test_fixed_timestep_zero_step() {
+    FixedTimestep::new(Duration::from_millis(0));
+}
+
+#[test]
+#[should_panic]
+fn test_run_fixed_zero_step() {
+    let game_loop = GameLoop::new(NoopGame { updates: Cell::new(0) });
+
+    game_loop.run_fixed(Duration::from_millis(0), |_| ());
+}
+
+#[test]
+fn test_run_fixed_render() {
+    let step = Duration::from_millis(1);
+    let game_loop = GameLoop::new(MockGame {
+        step: step,
+        updates: Cell::new(0),
+        max_updates: 20
+    });
+    let mut alphas = vec![];
+
+    game_loop.run_fixed(step, |alpha| alphas.push(alpha));
+
+    assert_eq!(game_loop.game.updates.get(), 20);
+    assert!(!alphas.is_empty());
+    assert!(alphas.iter().all(|&alpha| (0.0..1.0).contains(&alpha)));
+}
+
+struct PausingGame {
+    paused: Arc<AtomicBool>,
+    updates: Cell<u32>,
+    paused_elapsed: Cell<Duration>
+}
+
+impl Game for PausingGame {
+    fn update(&self, dt: Duration) -> bool {
+        self.updates.set(self.updates.get() + 1);
+
+        if self.paused.load(Ordering::SeqCst) {
+            self.paused_elapsed.set(self.paused_elapsed.get() + dt);
+        }
+
+        if self.updates.get() == 5 {
+            self.paused.store(false, Ordering::SeqCst);
+        }
+
+        self.updates.get() < 8
+    }
+}
+
+#[test]
+fn test_paused() {
+    let paused = Arc::new(AtomicBool::new(true));
+    let game_loop = GameLoop::new_pausable(PausingGame {
+        paused: paused.clone(),
+        updates: Cell::new(0),
+        paused_elapsed: Cell::new(Duration::from_millis(0))
+    }, paused);
+
+    game_loop.run();
+
+    assert_eq!(game_loop.game.updates.get(), 8);
+    assert_eq!(game_loop.game.paused_elapsed.get(), Duration::from_millis(0));
+    assert!(!game_loop.paused.load(Ordering::SeqCst));
+}
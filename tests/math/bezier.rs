@@ -0,0 +1,355 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+extern crate anima_engine;
+
+use std::f32::consts;
+
+use self::anima_engine::math::{Bezier, BezierPath, Curve, Plane, Vector};
+
+const EPSILON: f32 = 0.00001;
+
+#[test]
+fn test_elevate() {
+    let b = Bezier::new_sqr(
+        Vector::new(1.0, -2.0, 0.5),
+        Vector::new(4.0, 3.0, -1.0),
+        Vector::new(-2.0, 5.0, 2.0)
+    );
+    let e = b.elevate();
+
+    for i in 0..11 {
+        let ratio = i as f32 / 10.0;
+
+        assert!(b.interpolate(ratio).dist(e.interpolate(ratio)) < EPSILON);
+    }
+}
+
+#[test]
+fn test_elevate_cub() {
+    let b = Bezier::new_cub(
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+        Vector::new(1.0, 1.0, 0.0),
+        Vector::new(1.0, 0.0, 0.0)
+    );
+
+    assert_eq!(b.elevate(), b);
+}
+
+#[test]
+fn test_path_segments() {
+    let b1 = Bezier::new_sqr(
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(1.0, 2.0, 0.0),
+        Vector::new(2.0, 0.0, 0.0)
+    );
+    let b2 = Bezier::new_cub(
+        Vector::new(2.0, 0.0, 0.0),
+        Vector::new(2.0, -1.0, 1.0),
+        Vector::new(4.0, -1.0, 1.0),
+        Vector::new(5.0, 3.0, 0.0)
+    );
+    let b3 = Bezier::new_sqr(
+        Vector::new(5.0, 3.0, 0.0),
+        Vector::new(5.0, 4.0, 0.0),
+        Vector::new(6.0, 4.0, 0.0)
+    );
+    let p = BezierPath::new(vec![b1, b2, b3]);
+
+    let sum = p.segments().fold(0.0, |s, (_, l)| s + l);
+
+    assert!((sum - 1.0).abs() < EPSILON);
+    assert!(p.segments().zip(&p).all(|((c1, _), c2)| c1 == c2));
+    assert_eq!((&p).into_iter().count(), 3);
+}
+
+#[test]
+fn test_flatten_adaptive() {
+    let straight = Bezier::new_cub(
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(1.0, 0.001, 0.0),
+        Vector::new(2.0, -0.001, 0.0),
+        Vector::new(3.0, 0.0, 0.0)
+    );
+    let sharp = Bezier::new_cub(
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(0.0, 3.0, 0.0),
+        Vector::new(1.0, 3.0, 0.0),
+        Vector::new(1.0, 0.0, 0.0)
+    );
+
+    let straight_points = straight.flatten(0.01);
+    let sharp_points = sharp.flatten(0.01);
+
+    assert_eq!(straight_points.len(), 2);
+    assert!(sharp_points.len() > 10);
+
+    assert_eq!(sharp_points[0], sharp.interpolate(0.0));
+    assert_eq!(sharp_points[sharp_points.len() - 1], sharp.interpolate(1.0));
+}
+
+#[test]
+fn test_path_cached_lengths() {
+    let b1 = Bezier::new_sqr(
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(1.0, 2.0, 0.0),
+        Vector::new(2.0, 0.0, 0.0)
+    );
+    let b2 = Bezier::new_cub(
+        Vector::new(2.0, 0.0, 0.0),
+        Vector::new(2.0, -1.0, 1.0),
+        Vector::new(4.0, -1.0, 1.0),
+        Vector::new(5.0, 3.0, 0.0)
+    );
+    let p = BezierPath::new(vec![b1, b2]);
+
+    let fresh = b1.len(20) + b2.len(20);
+
+    assert_eq!(p.len(20), fresh);
+
+    for (curve, length) in p.segments() {
+        assert!((length * p.length - curve.len(20)).abs() < EPSILON);
+    }
+
+    assert!((p.len(200) - (b1.len(200) + b2.len(200))).abs() < EPSILON);
+}
+
+#[test]
+fn test_offset_straight() {
+    let b = Bezier::new_cub(
+        Vector::new(1.0, 1.0, 0.0),
+        Vector::new(2.0, 2.0, 0.0),
+        Vector::new(3.0, 3.0, 0.0),
+        Vector::new(4.0, 4.0, 0.0)
+    );
+    let p = b.offset(2.0f32.sqrt(), 8);
+    let shift = Vector::new(-1.0, 1.0, 0.0);
+
+    assert_eq!(p.curves.len(), 8);
+
+    for i in 0..11 {
+        let ratio = i as f32 / 10.0;
+        let point = p.interpolate(ratio) - shift;
+
+        assert!((point.x - point.y).abs() < EPSILON);
+        assert!(point.x >= 1.0 - EPSILON && point.x <= 4.0 + EPSILON);
+    }
+
+    assert!((p.interpolate(0.0) - shift).dist(b.interpolate(0.0)) < EPSILON);
+    assert!((p.interpolate(1.0) - shift).dist(b.interpolate(1.0)) < EPSILON);
+}
+
+#[test]
+fn test_point_and_tangent() {
+    let sqr = Bezier::new_sqr(
+        Vector::new(1.0, -2.0, 0.5),
+        Vector::new(4.0, 3.0, -1.0),
+        Vector::new(-2.0, 5.0, 2.0)
+    );
+    let cub = Bezier::new_cub(
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+        Vector::new(1.0, 1.0, 0.0),
+        Vector::new(1.0, 0.0, 0.0)
+    );
+
+    for b in vec![sqr, cub] {
+        for i in 0..11 {
+            let ratio = i as f32 / 10.0;
+            let (point, tangent) = b.point_and_tangent(ratio);
+
+            assert!(point.dist(b.interpolate(ratio)) < EPSILON);
+            assert!(tangent.dist(b.tangent(ratio)) < EPSILON);
+        }
+    }
+}
+
+fn sampled_len(b: &Bezier, steps: i32) -> f32 {
+    (1..steps + 1).fold(0.0, |l, i| {
+        let prev = b.interpolate((i - 1) as f32 / steps as f32);
+        let next = b.interpolate(i as f32 / steps as f32);
+
+        l + prev.dist(next)
+    })
+}
+
+#[test]
+fn test_sqr_len() {
+    let beziers = vec![
+        // curved
+        Bezier::new_sqr(
+            Vector::new(1.0, -2.0, 0.5),
+            Vector::new(4.0, 3.0, -1.0),
+            Vector::new(-2.0, 5.0, 2.0)
+        ),
+        // straight at constant speed
+        Bezier::new_sqr(
+            Vector::new(0.0, 0.0, 0.0),
+            Vector::new(1.0, 1.0, 0.0),
+            Vector::new(2.0, 2.0, 0.0)
+        ),
+        // straight, slowing down
+        Bezier::new_sqr(
+            Vector::new(0.0, 0.0, 0.0),
+            Vector::new(1.8, 0.0, 0.0),
+            Vector::new(2.0, 0.0, 0.0)
+        ),
+        // straight, turning back
+        Bezier::new_sqr(
+            Vector::new(0.0, 0.0, 0.0),
+            Vector::new(3.0, 0.0, 0.0),
+            Vector::new(2.0, 0.0, 0.0)
+        )
+    ];
+
+    for b in beziers {
+        assert!((b.len(20) - sampled_len(&b, 10000)).abs() < 0.001);
+    }
+}
+
+#[test]
+fn test_from_hermite() {
+    let p0 = Vector::new(1.0, -2.0, 0.5);
+    let t0 = Vector::new(4.0, 3.0, -1.0);
+    let p1 = Vector::new(-2.0, 5.0, 2.0);
+    let t1 = Vector::new(0.5, -6.0, 3.0);
+    let b = Bezier::from_hermite(p0, t0, p1, t1);
+
+    for i in 0..11 {
+        let t = i as f32 / 10.0;
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        let hermite = p0 * (2.0 * t3 - 3.0 * t2 + 1.0) +
+                      t0 * (t3 - 2.0 * t2 + t) +
+                      p1 * (-2.0 * t3 + 3.0 * t2) +
+                      t1 * (t3 - t2);
+
+        assert!(b.interpolate(t).dist(hermite) < 0.0001);
+    }
+}
+
+#[test]
+fn test_new_with_steps() {
+    // approximation of radius 1.0 quarter circles
+    let curves = vec![
+        Bezier::new_cub(
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(1.0, 0.55228, 0.0),
+            Vector::new(0.55228, 1.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0)
+        ),
+        Bezier::new_cub(
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(-0.55228, 1.0, 0.0),
+            Vector::new(-1.0, 0.55228, 0.0),
+            Vector::new(-1.0, 0.0, 0.0)
+        )
+    ];
+
+    let coarse = BezierPath::new_with_steps(curves.clone(), 4);
+    let fine = BezierPath::new_with_steps(curves, 100);
+
+    assert_eq!(coarse.len(4), coarse.length);
+    assert_eq!(fine.len(100), fine.length);
+    assert!((fine.length - consts::PI).abs() < (coarse.length - consts::PI).abs());
+    assert!((fine.length - consts::PI).abs() < 0.001);
+}
+
+#[test]
+fn test_closest_point() {
+    let p = BezierPath::new(vec![
+        Bezier::new_sqr(
+            Vector::new(0.0, 0.0, 0.0),
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(2.0, 0.0, 0.0)
+        ),
+        Bezier::new_cub(
+            Vector::new(2.0, 0.0, 0.0),
+            Vector::new(3.0, 0.0, 0.0),
+            Vector::new(4.0, 1.0, 0.0),
+            Vector::new(4.0, 2.0, 0.0)
+        )
+    ]);
+    let target = p.curves[1].interpolate(0.75);
+
+    let (ratio, point) = p.closest_point(target + Vector::new(0.01, -0.01, 0.0), 100);
+
+    assert!(point.dist(target) < EPSILON);
+    assert!(p.interpolate(ratio).dist(target) < 0.001);
+    assert!(ratio > p.lengths[0]);
+}
+
+fn end_direction<C: Curve>(curve: &C) -> (Vector, Vector) {
+    (curve.interpolate(1.0), curve.tangent(1.0))
+}
+
+fn length(curve: &dyn Curve) -> f32 {
+    curve.len(20)
+}
+
+#[test]
+fn test_curve() {
+    let b = Bezier::new_sqr(
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(1.0, 0.0, 0.0),
+        Vector::new(2.0, 0.0, 0.0)
+    );
+    let p = BezierPath::new(vec![b, Bezier::new_sqr(
+        Vector::new(2.0, 0.0, 0.0),
+        Vector::new(2.0, 1.0, 0.0),
+        Vector::new(2.0, 2.0, 0.0)
+    )]);
+
+    assert_eq!(end_direction(&b), (Vector::new(2.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0)));
+    assert_eq!(end_direction(&p), (Vector::new(2.0, 2.0, 0.0), Vector::new(0.0, 1.0, 0.0)));
+
+    assert!((length(&b) - 2.0).abs() < EPSILON);
+    assert!((length(&p) - 4.0).abs() < EPSILON);
+}
+
+#[test]
+fn test_reverse() {
+    let path = BezierPath::new(vec![
+        Bezier::new_sqr(Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 2.0, 0.0),
+                        Vector::new(2.0, 0.0, 0.0)),
+        Bezier::new_cub(Vector::new(2.0, 0.0, 0.0), Vector::new(3.0, -1.0, 1.0),
+                        Vector::new(5.0, 1.0, 2.0), Vector::new(6.0, 0.0, 0.0))
+    ]);
+    let reversed = path.reverse();
+
+    for i in 0..21 {
+        let ratio = i as f32 / 20.0;
+
+        assert!(reversed.interpolate(ratio).dist(path.interpolate(1.0 - ratio)) < EPSILON);
+    }
+
+    assert_eq!(reversed.reverse(), path);
+}
+
+#[test]
+fn test_intersect_plane() {
+    let b = Bezier::new_cub(
+        Vector::new(0.0, 1.0, 0.0),
+        Vector::new(1.0, -2.0, 0.0),
+        Vector::new(2.0, -2.0, 0.0),
+        Vector::new(3.0, 1.0, 0.0)
+    );
+    let ground = Plane::new(Vector::up(), 0.0);
+
+    let ratios = b.intersect_plane(&ground, 20);
+
+    assert_eq!(ratios.len(), 2);
+    assert!(ratios[0] < 0.5 && ratios[1] > 0.5);
+
+    for ratio in ratios {
+        assert!(b.interpolate(ratio).y.abs() < EPSILON);
+    }
+
+    assert!(b.intersect_plane(&Plane::new(Vector::up(), 2.0), 20).is_empty());
+}
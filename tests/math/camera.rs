@@ -0,0 +1,41 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+extern crate anima_engine;
+
+use std::f32::consts;
+
+use self::anima_engine::math::{Camera, Matrix, Quaternion, Vector};
+
+#[test]
+fn test_round_trip() {
+    let view = Matrix::ident()
+               .rot(Quaternion::new_rot(Vector::up(), consts::PI / 6.0))
+               .trans(Vector::new(0.5, -1.0, -4.0));
+    let camera = Camera::new(view, Matrix::frustum(-0.8, 1.2, -0.6, 0.6, 0.5, 50.0));
+
+    let eye = view.inv() * Vector::zero();
+    let point = Vector::new(0.3, 0.7, 1.5);
+
+    let (x, y, depth) = camera.world_to_screen(point, 1024.0, 768.0);
+
+    assert!(0.0 < depth && depth < 1.0);
+
+    let ray = camera.screen_to_ray(x, y, 1024.0, 768.0).unwrap();
+
+    assert!(point.dist_to_line(ray.origin, ray.at(1.0)) < 0.001);
+    assert!(eye.dist_to_line(ray.origin, ray.at(1.0)) < 0.001);
+    assert!((point - ray.origin).dot(ray.dir) > 0.0);
+}
+
+#[test]
+fn test_screen_to_ray_singular() {
+    let proj = Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0);
+    let camera = Camera::new(Matrix::new([0.0; 16]), proj);
+
+    assert_eq!(camera.screen_to_ray(512.0, 384.0, 1024.0, 768.0), None);
+}
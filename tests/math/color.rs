@@ -0,0 +1,57 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+extern crate anima_engine;
+
+use self::anima_engine::math::{Behavior, Color, Interpolate, Interpolator};
+
+const EPSILON: f32 = 0.00001;
+
+#[test]
+fn test_hex_round_trip() {
+    for hex in vec![0x00000000, 0xffffffff, 0x12345678, 0xff8000c0, 0x0a0b0c0d] {
+        assert_eq!(Color::from_hex(hex).to_hex(), hex);
+    }
+}
+
+#[test]
+fn test_linear_round_trip() {
+    let c = Color::from_hex(0x3366ccff);
+    let r = c.to_linear().to_srgb();
+
+    assert!((c.r - r.r).abs() < EPSILON);
+    assert!((c.g - r.g).abs() < EPSILON);
+    assert!((c.b - r.b).abs() < EPSILON);
+    assert_eq!(c.a, r.a);
+}
+
+#[test]
+fn test_interpolate_midpoint() {
+    let red = Color::from_hex(0xff0000ff);
+    let green = Color::from_hex(0x00ff0000);
+
+    let srgb = red.lerp(green, 0.5);
+    let linear = red.interpolate(green, 0.5);
+
+    assert_eq!(srgb.to_hex(), 0x80800080);
+    assert_eq!(linear.to_hex(), 0xbcbc0080);
+    assert!(linear.r > srgb.r && linear.g > srgb.g);
+    assert_eq!(linear.a, srgb.a);
+}
+
+#[test]
+fn test_tween_black_white() {
+    let black = Color::from_hex(0x000000ff);
+    let white = Color::from_hex(0xffffffff);
+    let i = Interpolator::new(1.0, 2.0, Behavior::Linear);
+
+    let tweened = black.interpolate(white, i.ratio(2.0));
+
+    assert_eq!(tweened, black.interpolate(white, 0.5));
+    assert_eq!(tweened.to_hex(), 0xbcbcbcff);
+    assert_eq!(black.interpolate_clamped(white, i.ratio(4.0)).to_hex(), 0xffffffff);
+}
@@ -0,0 +1,37 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+extern crate anima_engine;
+
+use std::f32::consts;
+
+use self::anima_engine::math::{Aabb, Frustum, Matrix, Quaternion, Vector};
+
+#[test]
+fn test_view_proj() {
+    let view = Matrix::ident()
+               .rot(Quaternion::new_rot(Vector::up(), consts::PI / 2.0))
+               .trans(Vector::new(0.0, 0.0, -5.0));
+    let proj = Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 100.0);
+    let frustum = Frustum::from_matrix(proj * view);
+
+    let eye = view.inv() * Vector::zero();
+    let ahead = view.inv() * Vector::new(0.0, 0.0, -10.0);
+    let behind = view.inv() * Vector::new(0.0, 0.0, 0.5);
+
+    assert!(frustum.contains_point(ahead));
+    assert!(!frustum.contains_point(behind));
+    assert!(!frustum.contains_point(eye));
+
+    for plane in frustum.planes.iter() {
+        assert!((plane.normal.len() - 1.0).abs() < 0.00001);
+    }
+
+    assert!(frustum.intersects_aabb(&Aabb::new(ahead - Vector::one(), ahead + Vector::one())));
+    assert!(!frustum.intersects_aabb(&Aabb::new(behind - Vector::new_unf(0.1),
+                                                behind + Vector::new_unf(0.1))));
+}
@@ -0,0 +1,67 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+extern crate anima_engine;
+
+use std::time::Duration;
+
+use self::anima_engine::math::{Behavior, Interpolator, PhasedInterpolator};
+
+#[test]
+fn test_ratio_dur() {
+    let i = Interpolator::new(1.0, 2.0, Behavior::AccDec);
+
+    for millis in vec![0, 1000, 1250, 2000, 2750, 3000] {
+        let dt = Duration::from_millis(millis);
+
+        assert_eq!(i.ratio_dur(dt), i.ratio(millis as f32 / 1000.0));
+    }
+}
+
+#[test]
+fn test_new_dur() {
+    let i1 = Interpolator::new(1.5, 2.0, Behavior::Acc);
+    let i2 = Interpolator::new_dur(Duration::from_millis(1500), Duration::from_secs(2),
+                                   Behavior::Acc);
+
+    assert_eq!(i1, i2);
+}
+
+#[test]
+fn test_behavior_round_trip() {
+    for behavior in vec![Behavior::Linear, Behavior::Acc, Behavior::Dec, Behavior::AccDec] {
+        assert_eq!(behavior.to_string().parse::<Behavior>(), Ok(behavior));
+    }
+
+    assert!("Linear".parse::<Behavior>().is_err());
+    assert!("".parse::<Behavior>().is_err());
+}
+
+#[test]
+fn test_with_delay() {
+    let i = Interpolator::new(0.0, 1.0, Behavior::AccDec).with_delay(2.0);
+
+    for &time in [-1.0, 0.0, 1.0, 1.5, 2.0].iter() {
+        assert_eq!(i.ratio(time), 0.0);
+    }
+
+    assert!(i.ratio(2.1) > 0.0);
+    assert!(i.ratio(2.5) > i.ratio(2.1));
+    assert_eq!(i.ratio(3.0), 1.0);
+    assert_eq!(i.ratio(10.0), 1.0);
+}
+
+#[test]
+fn test_phased_boundary() {
+    let i = PhasedInterpolator::new(vec![(1.0, Behavior::AccDec), (3.0, Behavior::Dec)]);
+
+    assert_eq!(i.ratio(0.0), 0.0);
+    assert_eq!(i.ratio(1.0), 0.25);
+    assert!(i.ratio(0.999) < 0.25);
+    assert!(i.ratio(1.001) > 0.25);
+    assert_eq!(i.ratio(4.0), 1.0);
+}
@@ -9,7 +9,7 @@ extern crate anima_engine;
 
 use std::f32::consts;
 
-use self::anima_engine::math::{Matrix, Vector, Quaternion};
+use self::anima_engine::math::{CachedMatrix, Matrix, MatrixStack, Vector, Quaternion};
 
 #[test]
 fn test_mul() {
@@ -62,3 +62,254 @@ fn test_det_0_panic() {
 
     m.inv();
 }
+
+#[test]
+fn test_is_identity_orthogonal_rot() {
+    let m = Matrix::ident()
+            .rot(Quaternion::new_rot(Vector::new(1.0, 2.0, 3.0), consts::PI / 3.0))
+            .trans(Vector::new(1.0, 2.0, 3.0));
+
+    assert!(!m.is_identity(0.00001));
+    assert!(m.is_orthogonal(0.00001));
+}
+
+#[test]
+fn test_is_identity_orthogonal_scale() {
+    let m = Matrix::ident().scale(Vector::new(1.0, 2.0, 1.0));
+
+    assert!(!m.is_identity(0.00001));
+    assert!(!m.is_orthogonal(0.00001));
+}
+
+#[test]
+fn test_viewport_corners() {
+    let m = Matrix::viewport(10.0, 20.0, 640.0, 480.0);
+
+    assert_eq!(m * Vector::new(-1.0, -1.0, -1.0), Vector::new(10.0, 20.0, 0.0));
+    assert_eq!(m * Vector::new(1.0, -1.0, 0.0), Vector::new(650.0, 20.0, 0.5));
+    assert_eq!(m * Vector::new(-1.0, 1.0, 0.0), Vector::new(10.0, 500.0, 0.5));
+    assert_eq!(m * Vector::new(1.0, 1.0, 1.0), Vector::new(650.0, 500.0, 1.0));
+    assert_eq!(m * Vector::zero(), Vector::new(330.0, 260.0, 0.5));
+}
+
+#[test]
+fn test_frustum_off_center() {
+    let m = Matrix::frustum(0.0, 2.0, -1.0, 3.0, 1.0, 100.0);
+
+    let bottom_left = m * Vector::new(0.0, -1.0, -1.0);
+    let top_right = m * Vector::new(2.0, 3.0, -1.0);
+    let far = m * Vector::new(200.0, 300.0, -100.0);
+
+    assert!(bottom_left.dist(Vector::new(-1.0, -1.0, -1.0)) < 0.0001);
+    assert!(top_right.dist(Vector::new(1.0, 1.0, -1.0)) < 0.0001);
+    assert!(far.dist(Vector::new(1.0, 1.0, 1.0)) < 0.0001);
+}
+
+#[test]
+fn test_rotation_from_euler() {
+    let angles = vec![
+        (0.3, 0.0, 0.0),
+        (0.0, -1.2, 0.0),
+        (0.0, 0.0, 2.5),
+        (0.4, 1.1, -0.7),
+        (-2.0, 3.0, 1.5)
+    ];
+
+    for (pitch, yaw, roll) in angles {
+        let m1 = Matrix::rotation(pitch, yaw, roll);
+        let m2 = Matrix::ident().rot(Quaternion::from_euler(pitch, yaw, roll));
+
+        for i in 0..16 {
+            assert!((m1.array[i] - m2.array[i]).abs() < 0.00001);
+        }
+    }
+}
+
+#[test]
+fn test_orthonormalize_perturbed() {
+    let q = Quaternion::new_rot(Vector::new(1.0, -2.0, 0.5), 1.3);
+    let m = Matrix::ident().rot(q).trans(Vector::new(1.0, 2.0, 3.0));
+    let mut perturbed = m;
+
+    for i in 0..11 {
+        if i % 4 != 3 {
+            perturbed.array[i] += 0.001 * ((i * 7 % 5) as f32 - 2.0);
+        }
+    }
+
+    assert!(!perturbed.is_orthogonal(0.0001));
+
+    let o = perturbed.orthonormalize();
+
+    assert!(o.is_orthogonal(0.00001));
+    assert_eq!(&o.array[12..16], &m.array[12..16]);
+
+    for i in 0..16 {
+        assert!((o.array[i] - m.array[i]).abs() < 0.01);
+    }
+}
+
+#[test]
+fn test_try_from_slice() {
+    let values: Vec<f32> = (0..17).map(|i| i as f32).collect();
+
+    assert!(Matrix::try_from_slice(&values[..15]).is_err());
+    assert!(Matrix::try_from_slice(&values).is_err());
+    assert!(Matrix::try_from_slice(&[]).is_err());
+
+    let m = Matrix::try_from_slice(&values[..16]).unwrap();
+
+    for i in 0..16 {
+        assert_eq!(m.array[i], i as f32);
+    }
+}
+
+#[test]
+fn test_cached_matrix() {
+    let m1 = Matrix::ident().rot(Quaternion::new_rot(Vector::up(), 1.0)).trans(Vector::one());
+    let m2 = Matrix::ident().scale(Vector::new_unf(2.0));
+    let mut cached = CachedMatrix::new(m1);
+
+    assert!(!cached.is_inv_cached());
+    assert_eq!(cached.inv(), m1.inv());
+    assert!(cached.is_inv_cached());
+    assert_eq!(cached.inv(), m1.inv());
+
+    cached.set(m2);
+
+    assert!(!cached.is_inv_cached());
+    assert_eq!(cached.inv(), m2.inv());
+    assert!(cached.is_inv_cached());
+}
+
+#[test]
+fn test_cached_matrix_eq_ignores_cache() {
+    let m = Matrix::ident().trans(Vector::one());
+    let cached = CachedMatrix::new(m);
+    let other = CachedMatrix::new(m);
+
+    assert_eq!(cached, other);
+
+    cached.inv();
+
+    assert!(cached.is_inv_cached());
+    assert!(!other.is_inv_cached());
+    assert_eq!(cached, other);
+}
+
+#[test]
+fn test_shear() {
+    let v = Vector::new(1.0, 1.0, 1.0);
+
+    assert_eq!(Matrix::shear(2.0, 0.0, 0.0, 0.0, 0.0, 0.0) * v, Vector::new(3.0, 1.0, 1.0));
+    assert_eq!(Matrix::shear(0.0, 2.0, 0.0, 0.0, 0.0, 0.0) * v, Vector::new(3.0, 1.0, 1.0));
+    assert_eq!(Matrix::shear(0.0, 0.0, 2.0, 0.0, 0.0, 0.0) * v, Vector::new(1.0, 3.0, 1.0));
+    assert_eq!(Matrix::shear(0.0, 0.0, 0.0, 2.0, 0.0, 0.0) * v, Vector::new(1.0, 3.0, 1.0));
+    assert_eq!(Matrix::shear(0.0, 0.0, 0.0, 0.0, 2.0, 0.0) * v, Vector::new(1.0, 1.0, 3.0));
+    assert_eq!(Matrix::shear(0.0, 0.0, 0.0, 0.0, 0.0, 2.0) * v, Vector::new(1.0, 1.0, 3.0));
+
+    let m = Matrix::shear(0.5, 0.0, 0.0, 0.0, 0.0, 0.0);
+
+    assert_eq!(m * Vector::new(0.0, 2.0, 0.0), Vector::new(1.0, 2.0, 0.0));
+    assert_eq!(m * Vector::new(0.0, 0.0, 2.0), Vector::new(0.0, 0.0, 2.0));
+}
+
+#[test]
+fn test_adjugate_inv() {
+    let m = Matrix::ident()
+            .rot(Quaternion::new_rot(Vector::up(), consts::PI / 3.0))
+            .scale(Vector::new(2.0, 3.0, 0.5))
+            .trans(Vector::new(1.0, -2.0, 4.0));
+
+    let adjugate = m.adjugate();
+    let inverse = m.inv();
+
+    // Every matrix satisfies m · adj(m) = det(m) · I, so the adjugate's diagonal after
+    // multiplication gives the determinant.
+    let det = (m * adjugate).array[0];
+
+    assert!((det - 3.0).abs() < 0.0001);
+
+    let mut array = adjugate.array;
+
+    for value in array.iter_mut() {
+        *value /= det;
+    }
+
+    assert!(Matrix::new(array).approx_eq(inverse, 0.00001));
+    assert_eq!(m.trace(), m.array[0] + m.array[5] + m.array[10] + m.array[15]);
+}
+
+#[test]
+fn test_matrix_stack() {
+    let t = Matrix::ident().trans(Vector::new(1.0, 0.0, 0.0));
+    let r = Matrix::ident().rot(Quaternion::new_rot(Vector::up(), consts::PI / 2.0));
+
+    let mut stack = MatrixStack::new();
+
+    stack.mul(t);
+    stack.push();
+    stack.mul(r);
+    stack.push();
+    stack.mul(t);
+
+    assert_eq!(stack.depth(), 3);
+    assert_eq!(stack.top(), t * r * t);
+
+    stack.pop();
+
+    assert_eq!(stack.top(), t * r);
+
+    stack.pop();
+
+    assert_eq!(stack.top(), t);
+    assert_eq!(stack.depth(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_matrix_stack_underflow() {
+    MatrixStack::new().pop();
+}
+
+#[test]
+fn test_billboard() {
+    let position = Vector::new(1.0, 2.0, 3.0);
+    let camera_pos = Vector::new(-4.0, 6.0, 0.5);
+
+    let m = Matrix::billboard(position, camera_pos, Vector::up());
+
+    let forward = m * Vector::forward() - position;
+    let up = m * Vector::up() - position;
+
+    assert!((forward - (camera_pos - position).norm()).len() < 0.00001);
+    assert!(forward.dot(up).abs() < 0.00001);
+    assert!(up.dot(Vector::up()) > 0.0);
+    assert!(m.is_orthogonal(0.00001));
+
+    let m = Matrix::billboard(position, position + Vector::up() * 3.0, Vector::up());
+    let forward = m * Vector::forward() - position;
+
+    assert!(m.is_finite());
+    assert!((forward - Vector::up()).len() < 0.00001);
+}
+
+#[test]
+fn test_project_unproject() {
+    let view = Matrix::ident()
+               .rot(Quaternion::new_rot(Vector::new(1.0, 1.0, 0.0), 0.3))
+               .trans(Vector::new(0.0, -1.0, -6.0));
+    let m = Matrix::frustum(-0.5, 0.7, -0.4, 0.4, 0.5, 20.0) * view;
+    let viewport = (10.0, 20.0, 640.0, 480.0);
+
+    let point = Vector::new(0.5, 1.5, -1.0);
+    let projected = m.project(point, viewport);
+
+    assert!(projected.x > 10.0 && projected.x < 650.0);
+    assert!(projected.y > 20.0 && projected.y < 500.0);
+    assert!(projected.z > 0.0 && projected.z < 1.0);
+
+    let unprojected = m.unproject(projected, viewport).unwrap();
+
+    assert!(unprojected.dist(point) < 0.001);
+}
@@ -7,4 +7,14 @@
 
 mod vector;
 mod quaternion;
+mod quaternion_interp;
 mod matrix;
+mod interpolator;
+mod timeline;
+mod spring;
+mod transform;
+mod spatial_hash;
+mod bezier;
+mod camera;
+mod frustum;
+mod color;
@@ -7,7 +7,9 @@
 
 extern crate anima_engine;
 
-use self::anima_engine::math::Quaternion;
+use std::f32::consts;
+
+use self::anima_engine::math::{Matrix, Quaternion, Vector};
 
 #[test]
 fn test_mul() {
@@ -16,3 +18,200 @@ fn test_mul() {
 
     assert_eq!(q1 * q2, Quaternion::new(12.0, 0.0, 6.0, -4.0));
 }
+
+#[test]
+fn test_angle_to_rounding() {
+    let q = Quaternion::new_rot(Vector::one(), 3.0);
+
+    assert!(q.dot(q) > 1.0);
+    assert!(q.angle(q).is_nan());
+    assert_eq!(q.angle_to(q), 0.0);
+}
+
+#[test]
+fn test_angle_to_shortest() {
+    let q1 = Quaternion::new_rot(Vector::up(), consts::PI / 4.0);
+    let q2 = Quaternion::new_rot(Vector::up(), consts::PI * 7.0 / 4.0);
+
+    assert!((q1.angle_to(q2) - consts::PI / 2.0).abs() < 0.00001);
+    assert!((q1.angle(q2) - consts::PI * 3.0 / 2.0).abs() < 0.00001);
+}
+
+#[test]
+fn test_from_to_rotation() {
+    let from = Vector::new(1.0, 2.0, 3.0);
+    let to = Vector::new(-2.0, 0.5, 1.0);
+
+    let q = Quaternion::from_to_rotation(from, to);
+
+    assert!(from.norm().rot(q).dist(to.norm()) < 0.00001);
+}
+
+#[test]
+fn test_from_to_rotation_parallel() {
+    let from = Vector::new(1.0, 2.0, 3.0);
+
+    assert!(Quaternion::new_sph_rot(from, from * 2.0).is_nan());
+    assert_eq!(Quaternion::from_to_rotation(from, from * 2.0), Quaternion::ident());
+}
+
+#[test]
+fn test_from_to_rotation_opposing() {
+    for from in vec![Vector::new(1.0, 2.0, 3.0), Vector::left(), Vector::up()] {
+        let q = Quaternion::from_to_rotation(from, -from);
+
+        assert!(q.is_finite());
+        assert!(from.norm().rot(q).dist(-from.norm()) < 0.00001);
+    }
+}
+
+#[test]
+fn test_rotate_towards_step() {
+    let current = Quaternion::new_rot(Vector::new(1.0, 2.0, 0.5).norm(), 0.3);
+    let target = Quaternion::new_rot(Vector::new(-1.0, 0.0, 2.0).norm(), 2.5);
+    let max_radians = 0.2;
+
+    let gap = current.angle_to(target);
+    let step = current.rotate_towards(target, max_radians);
+
+    assert!((current.angle_to(step) - max_radians).abs() < 0.0001);
+    assert!((step.angle_to(target) - (gap - max_radians)).abs() < 0.0001);
+}
+
+#[test]
+fn test_rotate_towards_shortest() {
+    let current = Quaternion::ident();
+    let target = Quaternion::new_rot(Vector::up(), consts::PI * 3.0 / 2.0);
+
+    let step = current.rotate_towards(target, consts::PI / 4.0);
+    let expected = Quaternion::new_rot(Vector::up(), -consts::PI / 4.0);
+
+    assert!(step.angle_to(expected) < 0.001);
+    assert_eq!(current.rotate_towards(target, consts::PI / 2.0), target);
+}
+
+#[test]
+fn test_from_euler_order() {
+    let (pitch, yaw, roll) = (0.4, 1.1, -0.7);
+
+    let q = Quaternion::from_euler(pitch, yaw, roll);
+    let m = Matrix::ident().rot(Quaternion::new_rot(Vector::forward(), roll))
+                           .rot(Quaternion::new_rot(Vector::left(), pitch))
+                           .rot(Quaternion::new_rot(Vector::up(), yaw));
+    let v = Vector::new(1.0, 2.0, 3.0);
+
+    assert!((Matrix::ident().rot(q) * v).dist(m * v) < 0.00001);
+}
+
+#[test]
+fn test_canonicalize() {
+    let v = Vector::new(1.0, 2.0, 3.0);
+    let rotations = vec![
+        Quaternion::new_rot(Vector::up(), consts::PI * 3.0 / 2.0),
+        Quaternion::new_rot(Vector::new(1.0, -1.0, 2.0), -4.0),
+        -Quaternion::new_rot(Vector::left(), 0.5),
+        Quaternion::new_rot(Vector::forward(), 1.0)
+    ];
+
+    for q in rotations {
+        let c = q.canonicalize();
+
+        assert!(c.w >= 0.0);
+        assert!(v.rot(c).dist(v.rot(q)) < 0.00001);
+    }
+}
+
+#[cfg(feature = "hash")]
+#[test]
+fn test_hash_set() {
+    use std::collections::HashSet;
+
+    use self::anima_engine::math::QuaternionKey;
+
+    let q = Quaternion::new_rot(Vector::up(), 1.0);
+    let mut set = HashSet::new();
+
+    set.insert(QuaternionKey(q));
+    set.insert(QuaternionKey(Quaternion::ident()));
+
+    assert!(set.contains(&QuaternionKey(q)));
+    assert!(!set.contains(&QuaternionKey(-q)));
+}
+
+#[test]
+fn test_rotate_points() {
+    let q = Quaternion::new_rot(Vector::new(1.0, -2.0, 0.5), 2.0);
+    let points: Vec<Vector> = (0..10).map(|i| {
+        let i = i as f32;
+
+        Vector::new(i, 1.0 - i * 0.5, i * i * 0.1)
+    }).collect();
+
+    let rotated = q.rotate_points(&points);
+    let mut rotated_mut = points.clone();
+
+    q.rotate_points_mut(&mut rotated_mut);
+
+    assert_eq!(rotated, rotated_mut);
+
+    for (p, r) in points.iter().zip(rotated.iter()) {
+        assert!(p.rot(q).dist(*r) < 0.0001);
+    }
+}
+
+#[test]
+fn test_from_basis() {
+    let rotations = [
+        Quaternion::ident(),
+        Quaternion::new_rot(Vector::new(1.0, 2.0, 3.0), 1.2),
+        Quaternion::new_rot(Vector::new(1.0, 0.0, 0.0), consts::PI),
+        Quaternion::new_rot(Vector::new(0.0, 1.0, 0.1), consts::PI * 0.9),
+        Quaternion::new_rot(Vector::new(0.2, 0.1, -1.0), consts::PI * 0.95)
+    ];
+
+    for &q in rotations.iter() {
+        let r = Quaternion::from_basis(Vector::right().rot(q), Vector::up().rot(q),
+                                       Vector::forward().rot(q));
+
+        // q and -q represent the same rotation.
+        assert!((r.dot(q).abs() - 1.0).abs() < 0.0001, "{:?} != {:?}", r, q);
+    }
+}
+
+#[test]
+fn test_to_axis_angle_small() {
+    let axis = Vector::new(1.0, 2.0, -1.0).norm();
+    let (small_axis, angle) = Quaternion::new_rot(axis, 0.001).to_axis_angle();
+
+    assert!(small_axis.dist(axis) < 0.0001);
+    assert!((angle - 0.001).abs() < 0.000001);
+
+    let q = Quaternion::new_rot(axis, 0.7);
+    let (delta_axis, angle) = q.delta_axis_angle(Quaternion::new_rot(axis, 0.701));
+
+    assert!(delta_axis.dist(axis) < 0.01);
+    assert!((angle - 0.001).abs() < 0.0001);
+}
+
+#[test]
+fn test_delta_axis_angle() {
+    let axis = Vector::new(1.0, 2.0, -1.0).norm();
+    let q1 = Quaternion::new_rot(axis, 0.7);
+    let q2 = Quaternion::new_rot(axis, 0.7 + consts::PI / 2.0);
+
+    let (delta_axis, angle) = q1.delta_axis_angle(q2);
+
+    assert!(delta_axis.dist(axis) < 0.0001);
+    assert!((angle - consts::PI / 2.0).abs() < 0.0001);
+
+    // -q2 is the same rotation, so the result doesn't change.
+    let (delta_axis, angle) = q1.delta_axis_angle(-q2);
+
+    assert!(delta_axis.dist(axis) < 0.0001);
+    assert!((angle - consts::PI / 2.0).abs() < 0.0001);
+
+    let (back_axis, back_angle) = q2.delta_axis_angle(q1);
+
+    assert!(back_axis.dist(-axis) < 0.0001);
+    assert!((back_angle - consts::PI / 2.0).abs() < 0.0001);
+}
@@ -0,0 +1,95 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+extern crate anima_engine;
+
+use std::f32::consts;
+
+use self::anima_engine::math::{Interpolate, Quaternion, Vector};
+
+const EPSILON: f32 = 0.0001;
+
+fn neg(q: Quaternion) -> Quaternion {
+    Quaternion::new(-q.x, -q.y, -q.z, -q.w)
+}
+
+fn same_rot(q1: Quaternion, q2: Quaternion) -> bool {
+    let close = |q: Quaternion| {
+        (q1.x - q.x).abs() < EPSILON &&
+        (q1.y - q.y).abs() < EPSILON &&
+        (q1.z - q.z).abs() < EPSILON &&
+        (q1.w - q.w).abs() < EPSILON
+    };
+
+    close(q2) || close(neg(q2))
+}
+
+#[test]
+fn test_endpoints() {
+    let q1 = Quaternion::new_rot(Vector::up(), consts::PI / 6.0);
+    let q2 = Quaternion::new_rot(Vector::new(1.0, 1.0, 0.0), consts::PI / 2.0);
+
+    assert!(same_rot(q1.interpolate(q2, 0.0), q1));
+    assert!(same_rot(q1.interpolate(q2, 1.0), q2));
+}
+
+#[test]
+fn test_identity_endpoints() {
+    let q = Quaternion::ident();
+
+    assert!(same_rot(q.interpolate(q, 0.0), q));
+    assert!(same_rot(q.interpolate(q, 0.5), q));
+    assert!(same_rot(q.interpolate(q, 1.0), q));
+}
+
+#[test]
+fn test_nlerp_fallback() {
+    let q1 = Quaternion::new_rot(Vector::up(), 1.0);
+    let q2 = Quaternion::new_rot(Vector::up(), 1.00001);
+
+    let qi = q1.interpolate(q2, 0.5);
+
+    assert!(qi.is_finite());
+    assert!((qi.dot(qi) - 1.0).abs() < EPSILON);
+    assert!(same_rot(qi, q1));
+}
+
+#[test]
+fn test_opposite_sign() {
+    let q = Quaternion::new_rot(Vector::new(1.0, 2.0, 3.0), consts::PI / 3.0);
+
+    let qi = q.interpolate(neg(q), 0.5);
+
+    assert!(qi.is_finite());
+    assert!(same_rot(qi, q));
+}
+
+#[test]
+fn test_shortest_path() {
+    let q1 = Quaternion::new_rot(Vector::up(), 0.2);
+    let q2 = neg(Quaternion::new_rot(Vector::up(), 0.6));
+
+    let qi = q1.interpolate(q2, 0.5);
+    let expected = Quaternion::new_rot(Vector::up(), 0.4);
+
+    assert!(same_rot(qi, expected));
+}
+
+#[test]
+fn test_lerp_nlerp_magnitude() {
+    let q1 = Quaternion::ident();
+    let q2 = Quaternion::new_rot(Vector::up(), consts::PI / 2.0);
+
+    let lerped = q1.lerp(q2, 0.5);
+    let nlerped = q1.nlerp(q2, 0.5);
+
+    // Both blends point the same way, but the plain one cuts the chord and is shorter.
+    assert!(same_rot(nlerped, q1.interpolate(q2, 0.5)));
+    assert!((lerped.dot(lerped).sqrt() - (consts::PI / 8.0).cos()).abs() < EPSILON);
+    assert!((nlerped.dot(nlerped) - 1.0).abs() < EPSILON);
+    assert!((lerped.dot(nlerped) - lerped.dot(lerped).sqrt()).abs() < EPSILON);
+}
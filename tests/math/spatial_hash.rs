@@ -0,0 +1,55 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+extern crate anima_engine;
+
+use std::f32;
+
+use self::anima_engine::math::{Aabb, SpatialHash, Vector};
+
+#[test]
+fn test_query_nearby() {
+    let mut h = SpatialHash::new(2.0);
+
+    h.insert(1, Aabb::new(Vector::new(0.5, 0.5, 0.5), Vector::new(1.0, 1.0, 1.0)));
+    h.insert(2, Aabb::new(Vector::new(1.5, 0.0, 0.0), Vector::new(2.5, 1.0, 1.0)));
+    h.insert(3, Aabb::new(Vector::new(-3.0, -3.0, -3.0), Vector::new(-2.5, -2.5, -2.5)));
+    h.insert(4, Aabb::new(Vector::new(10.0, 0.0, 0.0), Vector::new(11.0, 1.0, 1.0)));
+    h.insert(5, Aabb::new(Vector::new(-1.0, 0.0, 0.0), Vector::new(12.0, 0.5, 0.5)));
+
+    let near = Aabb::new(Vector::new(0.0, 0.0, 0.0), Vector::new(1.0, 1.0, 1.0));
+
+    assert_eq!(h.query(near), vec![1, 2, 5]);
+    assert_eq!(h.query(Aabb::new(Vector::new_unf(-2.9), Vector::new_unf(-2.8))), vec![3]);
+    assert!(h.query(Aabb::new(Vector::new_unf(50.0), Vector::new_unf(51.0))).is_empty());
+
+    h.clear();
+
+    assert!(h.query(near).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_zero_cell_size() {
+    SpatialHash::new(0.0);
+}
+
+#[test]
+#[should_panic]
+fn test_nan_cell_size() {
+    SpatialHash::new(f32::NAN);
+}
+
+#[test]
+fn test_saturated_cells() {
+    let mut h = SpatialHash::new(1.0);
+    let far = Aabb::new(Vector::new_unf(1e30), Vector::new_unf(2e30));
+
+    h.insert(1, far);
+
+    assert_eq!(h.query(far), vec![1]);
+}
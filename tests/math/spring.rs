@@ -0,0 +1,55 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+extern crate anima_engine;
+
+use self::anima_engine::math::{Spring, Vector};
+
+#[test]
+fn test_crit_settles() {
+    let s = Spring::new_crit(50.0);
+    let mut position = 0.0;
+    let mut velocity = 0.0;
+
+    for _ in 0..600 {
+        position = s.step(position, 2.0, &mut velocity, 1.0 / 60.0);
+
+        assert!(position <= 2.0);
+    }
+
+    assert!((position - 2.0).abs() < 0.0001);
+    assert!(velocity.abs() < 0.0001);
+}
+
+#[test]
+fn test_crit_settles_vec() {
+    let s = Spring::new_crit(50.0);
+    let target = Vector::new(1.0, -2.0, 3.0);
+    let mut position = Vector::zero();
+    let mut velocity = Vector::zero();
+
+    for _ in 0..600 {
+        position = s.step_vec(position, target, &mut velocity, 1.0 / 60.0);
+    }
+
+    assert!(position.dist(target) < 0.0001);
+}
+
+#[test]
+fn test_underdamped_overshoots() {
+    let s = Spring::new(50.0, 1.0);
+    let mut position = 0.0;
+    let mut velocity = 0.0;
+    let mut max: f32 = 0.0;
+
+    for _ in 0..600 {
+        position = s.step(position, 2.0, &mut velocity, 1.0 / 60.0);
+        max = max.max(position);
+    }
+
+    assert!(max > 2.0);
+}
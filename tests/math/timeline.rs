@@ -0,0 +1,36 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+extern crate anima_engine;
+
+use self::anima_engine::math::{Behavior, Interpolator, Timeline};
+
+#[test]
+fn test_sequential() {
+    let mut t = Timeline::new();
+
+    let first = t.add(Interpolator::new(0.0, 1.0, Behavior::Linear), 0.0);
+    let second = t.add(Interpolator::new(0.0, 2.0, Behavior::Acc), 1.0);
+
+    assert_eq!(t.sample(-0.5), vec![]);
+    assert_eq!(t.sample(0.0), vec![(first, 0.0)]);
+    assert_eq!(t.sample(0.5), vec![(first, 0.5)]);
+    assert_eq!(t.sample(1.0), vec![(first, 1.0), (second, 0.0)]);
+    assert_eq!(t.sample(2.0), vec![(second, 0.25)]);
+    assert_eq!(t.sample(3.5), vec![]);
+}
+
+#[test]
+fn test_end_of_last_entry() {
+    let mut t = Timeline::new();
+
+    t.add(Interpolator::new(0.0, 1.0, Behavior::Linear), 0.0);
+    let last = t.add(Interpolator::new(0.5, 1.5, Behavior::AccDec), 1.0);
+
+    assert_eq!(t.sample(3.0), vec![(last, 1.0)]);
+    assert_eq!(t.sample(3.001), vec![]);
+}
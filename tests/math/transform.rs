@@ -0,0 +1,90 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+extern crate anima_engine;
+
+use std::f32::consts;
+
+use self::anima_engine::math::{Interpolate, Matrix, Quaternion, Transform, Vector};
+
+const EPSILON: f32 = 0.0001;
+
+fn assert_matrix_eq(m1: Matrix, m2: Matrix) {
+    for i in 0..16 {
+        assert!((m1.array[i] - m2.array[i]).abs() < EPSILON, "{:?} != {:?}", m1, m2);
+    }
+}
+
+#[test]
+fn test_combine() {
+    let parent = Transform::new(
+        Vector::new(1.0, 2.0, 3.0),
+        Quaternion::new_rot(Vector::up(), consts::PI / 3.0),
+        Vector::new_unf(2.0)
+    );
+    let child = Transform::new(
+        Vector::new(-1.0, 0.5, 2.0),
+        Quaternion::new_rot(Vector::new(1.0, 1.0, 0.0), consts::PI / 5.0),
+        Vector::new(1.0, 3.0, 0.5)
+    );
+
+    assert_matrix_eq(parent.combine(&child).to_matrix(), parent.to_matrix() * child.to_matrix());
+}
+
+#[test]
+fn test_transform_point() {
+    let t = Transform::new(
+        Vector::new(1.0, 2.0, 3.0),
+        Quaternion::new_rot(Vector::forward(), consts::PI / 4.0),
+        Vector::new(1.0, 2.0, 3.0)
+    );
+    let v = Vector::new(3.0, -1.0, 0.5);
+
+    assert!(t.transform_point(v).dist(t.to_matrix() * v) < EPSILON);
+}
+
+#[test]
+fn test_inverse() {
+    let t = Transform::new(
+        Vector::new(1.0, 2.0, 3.0),
+        Quaternion::new_rot(Vector::new(1.0, 2.0, 0.0), consts::PI / 3.0),
+        Vector::new_unf(4.0)
+    );
+
+    assert_matrix_eq(t.inverse().to_matrix(), t.to_matrix().inv());
+}
+
+#[test]
+fn test_interpolate() {
+    let t1 = Transform::new(
+        Vector::new(1.0, 2.0, 3.0),
+        Quaternion::new_rot(Vector::up(), consts::PI / 6.0),
+        Vector::new_unf(1.0)
+    );
+    let t2 = Transform::new(
+        Vector::new(3.0, 2.0, 1.0),
+        Quaternion::new_rot(Vector::new(1.0, 1.0, 0.0), consts::PI / 2.0),
+        Vector::new_unf(3.0)
+    );
+
+    let half = t1.interpolate(t2, 0.5);
+
+    assert!(half.position.dist(Vector::new_unf(2.0)) < EPSILON);
+    assert!(half.scale.dist(Vector::new_unf(2.0)) < EPSILON);
+    assert!((t1.rotation.angle(half.rotation) - t1.rotation.angle(t2.rotation) / 2.0).abs() <
+            EPSILON);
+    assert!((half.rotation.angle(t2.rotation) - t1.rotation.angle(t2.rotation) / 2.0).abs() <
+            EPSILON);
+}
+
+#[test]
+fn test_interpolate_same_rotation() {
+    let t1 = Transform::ident();
+    let t2 = Transform::new(Vector::one(), Quaternion::ident(), Vector::one());
+
+    assert_eq!(t1.interpolate(t2, 0.5).rotation, Quaternion::ident());
+}
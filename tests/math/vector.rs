@@ -7,7 +7,8 @@
 
 extern crate anima_engine;
 
-use self::anima_engine::math::Vector;
+use self::anima_engine::math;
+use self::anima_engine::math::{Interpolate, Matrix, Quaternion, Vector};
 
 #[test]
 fn test_add() {
@@ -39,3 +40,184 @@ fn test_neg() {
 
     assert_eq!(-v, Vector::new(-1.0, -1.0, -1.0));
 }
+
+#[test]
+fn test_is_nan() {
+    assert!(Vector::zero().norm().is_nan());
+    assert!(!Vector::zero().norm().is_finite());
+}
+
+#[test]
+fn test_is_finite() {
+    let v = Vector::new(1.0, 2.0, 2.0).norm();
+
+    assert!(v.is_finite());
+    assert!(!v.is_nan());
+}
+
+#[test]
+fn test_barycentric_centroid() {
+    let a = Vector::new(1.0, 0.0, 2.0);
+    let b = Vector::new(4.0, 1.0, -1.0);
+    let c = Vector::new(-2.0, 3.0, 0.5);
+    let centroid = (a + b + c) * (1.0 / 3.0);
+
+    let (u, v, w) = math::try_barycentric(centroid, a, b, c).unwrap();
+
+    assert!((u - 1.0 / 3.0).abs() < 0.00001);
+    assert!((v - 1.0 / 3.0).abs() < 0.00001);
+    assert!((w - 1.0 / 3.0).abs() < 0.00001);
+    assert!(Vector::from_barycentric(a, b, c, (u, v, w)).dist(centroid) < 0.00001);
+}
+
+#[test]
+fn test_barycentric_degenerate() {
+    let a = Vector::zero();
+    let b = Vector::one();
+
+    assert_eq!(math::try_barycentric(Vector::up(), a, b, b * 3.0), None);
+    assert_eq!(math::try_barycentric(Vector::up(), a, a, a), None);
+}
+
+#[test]
+fn test_barycentric_small() {
+    let a = Vector::new(0.0, 0.0, 0.0);
+    let b = Vector::new(0.001, 0.0, 0.0);
+    let c = Vector::new(0.0, 0.001, 0.0);
+
+    let (u, v, w) = math::try_barycentric(Vector::new(0.0005, 0.0005, 0.0), a, b, c).unwrap();
+
+    assert!(u.abs() < 0.00001);
+    assert!((v - 0.5).abs() < 0.00001);
+    assert!((w - 0.5).abs() < 0.00001);
+}
+
+#[test]
+fn test_inverse_lerp_short() {
+    let a = Vector::new(1.0, 1.0, 1.0);
+    let b = Vector::new(1.0001, 1.0, 1.0);
+
+    assert!((a.interpolate(b, 0.25).inverse_lerp(a, b) - 0.25).abs() < 0.01);
+}
+
+#[test]
+fn test_spherical_round_trip() {
+    let vectors = vec![
+        Vector::new(1.0, 2.0, 3.0),
+        Vector::new(-4.0, 0.5, -0.25),
+        Vector::new(0.0, -3.0, 1.0),
+        Vector::new(-2.0, 0.0, 0.0),
+        Vector::new(0.0, 0.0, -1.0),
+        Vector::down()
+    ];
+
+    for v in vectors {
+        let (radius, theta, phi) = v.to_spherical();
+
+        assert!(Vector::from_spherical(radius, theta, phi).dist(v) < 0.00001);
+    }
+}
+
+#[test]
+fn test_array4_point() {
+    let v = Vector::new(1.0, -2.0, 3.0);
+
+    assert_eq!(Vector::from_array4(v.to_array4(1.0)), v);
+    assert_eq!(Vector::from_array4([4.0, -8.0, 12.0, 4.0]), v);
+    assert_eq!(Vector::from_array4([-1.0, 2.0, -3.0, -1.0]), v);
+}
+
+#[test]
+fn test_array4_direction() {
+    let v = Vector::new(1.0, -2.0, 3.0);
+
+    assert_eq!(v.to_array4(0.0), [1.0, -2.0, 3.0, 0.0]);
+    assert_eq!(Vector::from_array4(v.to_array4(0.0)), v);
+}
+
+#[test]
+fn test_rotate_axes() {
+    let v = Vector::new(1.0, -2.0, 0.5);
+    let axes = vec![Vector::left(), Vector::up(), Vector::forward()];
+
+    for (i, axis) in axes.into_iter().enumerate() {
+        let m = Matrix::ident().rot(Quaternion::new_rot(axis, 0.7));
+
+        let rotated = match i {
+            0 => v.rotate_x(0.7),
+            1 => v.rotate_y(0.7),
+            _ => v.rotate_z(0.7)
+        };
+
+        assert!(rotated.dist(m * v) < 0.00001);
+    }
+}
+
+#[test]
+fn test_interpolate_clamped() {
+    let v1 = Vector::new(1.0, -2.0, 3.0);
+    let v2 = Vector::new(-4.0, 0.5, 2.0);
+
+    assert!(v1.interpolate(v2, 1.5).dist(v2) > 1.0);
+    assert_eq!(v1.interpolate_clamped(v2, 1.5), v2);
+    assert_eq!(v1.interpolate_clamped(v2, -3.0), v1);
+    assert_eq!(v1.interpolate_clamped(v2, 0.5), v1.interpolate(v2, 0.5));
+}
+
+#[test]
+fn test_orthonormal_basis() {
+    let directions = [
+        Vector::new(1.0, 2.0, 3.0),
+        Vector::new(-0.001, 5.0, 0.002),
+        Vector::new(0.0, 0.0, -1.0),
+        Vector::new(3.0, -3.0, 3.0)
+    ];
+
+    for &direction in directions.iter() {
+        let (x, y, z) = direction.orthonormal_basis();
+
+        assert!((x - direction.norm()).len() < 0.00001);
+
+        for &v in [x, y, z].iter() {
+            assert!((v.len() - 1.0).abs() < 0.00001);
+        }
+
+        assert!(x.dot(y).abs() < 0.00001);
+        assert!(y.dot(z).abs() < 0.00001);
+        assert!(z.dot(x).abs() < 0.00001);
+        assert!((x.cross(y) - z).len() < 0.00001);
+    }
+}
+
+#[test]
+fn test_weighted_sum() {
+    let points = [Vector::left(), Vector::up(), Vector::forward()];
+    let weights = [0.2, 0.3, 0.5];
+
+    let blended = Vector::weighted_sum(&points, &weights);
+
+    assert_eq!(blended, Vector::new(0.2, 0.3, 0.5));
+    assert_eq!(Vector::weighted_sum(&points, &[1.0 / 3.0; 3]), Vector::centroid(&points));
+}
+
+#[cfg(feature = "hash")]
+#[test]
+fn test_hash_set() {
+    use std::collections::HashSet;
+    use std::f32;
+
+    use self::anima_engine::math::VectorKey;
+
+    let mut set = HashSet::new();
+
+    set.insert(VectorKey(Vector::new(1.0, 2.0, 3.0)));
+    set.insert(VectorKey(Vector::new(1.0, 2.0, 3.0)));
+    set.insert(VectorKey(Vector::new(0.0, 0.0, 0.0)));
+    set.insert(VectorKey(Vector::new(f32::NAN, 0.0, 0.0)));
+
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&VectorKey(Vector::new(1.0, 2.0, 3.0))));
+    assert!(set.contains(&VectorKey(Vector::new(-0.0, 0.0, -0.0))));
+    assert!(set.contains(&VectorKey(Vector::new(f32::NAN, 0.0, 0.0))));
+    assert!(!set.contains(&VectorKey(Vector::new(1.0, 2.0, 3.0001))));
+}
@@ -0,0 +1,70 @@
+// Anima Engine. The quirky game engine
+// Copyright (C) 2016  Dragoș Tiselice
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+extern crate anima_engine;
+
+use std::f32::consts;
+
+use self::anima_engine::math::{Quaternion, Transform, Vector};
+use self::anima_engine::scene::Node;
+
+const EPSILON: f32 = 0.00001;
+
+#[test]
+fn test_parent_move() {
+    let parent = Node::new(Transform::ident());
+    let child = Node::new(Transform::new(Vector::forward(), Quaternion::ident(), Vector::one()));
+    let grandchild = Node::new(Transform::new(Vector::up(), Quaternion::ident(), Vector::one()));
+
+    Node::add_child(&parent, child.clone());
+    Node::add_child(&child, grandchild.clone());
+
+    assert_eq!(grandchild.borrow().world_position(), Vector::new(0.0, 1.0, 1.0));
+
+    parent.borrow_mut().set_local(Transform::new(Vector::new(2.0, 0.0, 0.0),
+                                                 Quaternion::ident(),
+                                                 Vector::one()));
+
+    assert_eq!(child.borrow().world_position(), Vector::new(2.0, 0.0, 1.0));
+    assert_eq!(grandchild.borrow().world_position(), Vector::new(2.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_parent_rotate() {
+    let parent = Node::new(Transform::ident());
+    let child = Node::new(Transform::new(Vector::forward(), Quaternion::ident(), Vector::one()));
+
+    Node::add_child(&parent, child.clone());
+
+    assert_eq!(child.borrow().world_position(), Vector::forward());
+
+    let rotation = Quaternion::new_rot(Vector::up(), consts::PI / 2.0);
+
+    parent.borrow_mut().set_local(Transform::new(Vector::zero(), rotation, Vector::one()));
+
+    let expected = Transform::new(Vector::zero(), rotation, Vector::one())
+                   .transform_point(Vector::forward());
+
+    assert!(child.borrow().world_position().dist(expected) < EPSILON);
+    assert!(child.borrow().world_position().dist(Vector::forward()) > 1.0);
+}
+
+#[test]
+fn test_reparent() {
+    let a = Node::new(Transform::new(Vector::up(), Quaternion::ident(), Vector::one()));
+    let b = Node::new(Transform::new(Vector::down(), Quaternion::ident(), Vector::one()));
+    let child = Node::new(Transform::ident());
+
+    Node::add_child(&a, child.clone());
+
+    assert_eq!(child.borrow().world_position(), Vector::up());
+
+    Node::add_child(&b, child.clone());
+
+    assert_eq!(child.borrow().world_position(), Vector::down());
+    assert_eq!(a.borrow().children().len(), 0);
+}